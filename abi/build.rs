@@ -14,6 +14,11 @@ fn main() {
                 "page",
                 "page_size",
                 "desc",
+                "statuses",
+                "match_mode",
+                "cursor",
+                "with_total_count",
+                "sort_by",
             ],
         )
         .with_builder_strip_option("reservation.ReservationQuery", &["start", "end"])
@@ -56,7 +61,7 @@ impl BuilderExt for tonic_build::Builder {
         fields.iter().fold(self, |builder, field| {
             builder.field_attribute(
                 format!("{path}.{field}"),
-                "#[builder(setter(into, strip_option))]",
+                "#[builder(setter(into, strip_option), default)]",
             )
         })
     }
@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use regex::Regex;
 use std::{collections::HashMap, convert::Infallible, str::FromStr};
 
+use crate::{ConflictWindow, ReservationConflictDetails, utc_time_to_timestamp};
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ReservationConflictInfo {
     Parsed(ReservationConflict),
@@ -12,6 +14,8 @@ pub enum ReservationConflictInfo {
 pub struct ReservationConflict {
     pub new: ReservationWindow,
     pub old: ReservationWindow,
+    /// the reservations only conflict through the buffers of the resource, windows include them.
+    pub buffered: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -21,6 +25,26 @@ pub struct ReservationWindow {
     pub end: DateTime<Utc>,
 }
 
+impl From<&ReservationWindow> for ConflictWindow {
+    fn from(window: &ReservationWindow) -> Self {
+        Self {
+            resource_id: window.rid.clone(),
+            start: Some(utc_time_to_timestamp(window.start)),
+            end: Some(utc_time_to_timestamp(window.end)),
+        }
+    }
+}
+
+impl From<&ReservationConflict> for ReservationConflictDetails {
+    fn from(conflict: &ReservationConflict) -> Self {
+        Self {
+            new: Some((&conflict.new).into()),
+            old: Some((&conflict.old).into()),
+            buffered: conflict.buffered,
+        }
+    }
+}
+
 impl FromStr for ReservationConflictInfo {
     type Err = Infallible;
 
@@ -44,9 +68,12 @@ impl FromStr for ReservationConflict {
 impl TryFrom<ParsedInfo> for ReservationConflict {
     type Error = ();
     fn try_from(value: ParsedInfo) -> Result<Self, Self::Error> {
+        // buffer conflicts are reported by the exclusion on the effective timespan.
+        let buffered = value.new.contains_key("effective_timespan");
         Ok(Self {
             new: value.new.try_into()?,
             old: value.old.try_into()?,
+            buffered,
         })
     }
 }
@@ -54,7 +81,11 @@ impl TryFrom<ParsedInfo> for ReservationConflict {
 impl TryFrom<HashMap<String, String>> for ReservationWindow {
     type Error = ();
     fn try_from(value: HashMap<String, String>) -> Result<Self, Self::Error> {
-        let timespan_str = value.get("timespan").ok_or(())?.replace('"', "");
+        let timespan_str = value
+            .get("timespan")
+            .or_else(|| value.get("effective_timespan"))
+            .ok_or(())?
+            .replace('"', "");
         let mut split = timespan_str.splitn(2, ',');
         let start = parse_pg_str_to_datetime(split.next().ok_or(())?.trim()).map_err(|_| ())?;
         let end = parse_pg_str_to_datetime(split.next().ok_or(())?.trim()).map_err(|_| ())?;
@@ -159,4 +190,20 @@ mod tests {
             ReservationConflictInfo::Unparsed(_) => panic!("Expected parsed conflict info"),
         }
     }
+
+    #[test]
+    fn buffer_conflict_error_message_should_parse() {
+        let msg = ERR_MSG.replace("timespan", "effective_timespan");
+        let ReservationConflictInfo::Parsed(conflict) = msg.parse().unwrap() else {
+            panic!("Expected parsed conflict info");
+        };
+        assert!(conflict.buffered);
+        assert_eq!(conflict.new.start.to_string(), "2025-06-02 19:00:00 UTC");
+        assert_eq!(conflict.old.end.to_string(), "2025-06-03 19:00:00 UTC");
+
+        let ReservationConflictInfo::Parsed(conflict) = ERR_MSG.parse().unwrap() else {
+            panic!("Expected parsed conflict info");
+        };
+        assert!(!conflict.buffered);
+    }
 }
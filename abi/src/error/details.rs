@@ -0,0 +1,58 @@
+use prost::{Message, Name};
+use prost_types::Any;
+use tonic::{Code, Status, codegen::Bytes};
+
+use crate::{ReservationConflictDetails, ReservationConflictsDetails};
+
+const TYPE_URL_PREFIX: &str = "type.googleapis.com";
+
+/// `google.rpc.Status` of the grpc rich error model, which grpc clients decode the status details as.
+#[derive(Clone, PartialEq, Message)]
+pub struct RpcStatus {
+    #[prost(int32, tag = "1")]
+    pub code: i32,
+    #[prost(string, tag = "2")]
+    pub message: String,
+    #[prost(message, repeated, tag = "3")]
+    pub details: Vec<Any>,
+}
+
+impl Name for ReservationConflictDetails {
+    const PACKAGE: &'static str = "reservation";
+    const NAME: &'static str = "ReservationConflictDetails";
+
+    fn type_url() -> String {
+        format!("{TYPE_URL_PREFIX}/{}", Self::full_name())
+    }
+}
+
+impl Name for ReservationConflictsDetails {
+    const PACKAGE: &'static str = "reservation";
+    const NAME: &'static str = "ReservationConflictsDetails";
+
+    fn type_url() -> String {
+        format!("{TYPE_URL_PREFIX}/{}", Self::full_name())
+    }
+}
+
+/// Builds a status whose details hold `details` packed in an `Any`.
+pub(crate) fn status_with_details<M: Name>(code: Code, message: String, details: &M) -> Status {
+    let status = RpcStatus {
+        code: code as i32,
+        message: message.clone(),
+        details: vec![Any {
+            type_url: M::type_url(),
+            value: details.encode_to_vec(),
+        }],
+    };
+    Status::with_details(code, message, Bytes::from(status.encode_to_vec()))
+}
+
+/// Reads the details of type `M` from the status, `None` if it carries none.
+pub fn decode_details<M: Name + Default>(status: &Status) -> Option<M> {
+    RpcStatus::decode(status.details())
+        .ok()?
+        .details
+        .iter()
+        .find_map(|any| any.to_msg().ok())
+}
@@ -1,9 +1,14 @@
 use sqlx::postgres::PgDatabaseError;
 use thiserror::Error;
+use tonic::{Code, Status};
+
+use crate::{ReservationConflictDetails, ReservationConflictsDetails, ReservationStatus};
 
 pub use crate::error::conflict::*;
+pub use crate::error::details::{RpcStatus, decode_details};
 
 mod conflict;
+mod details;
 
 #[derive(Error, Debug)]
 pub enum Error {
@@ -13,6 +18,9 @@ pub enum Error {
     #[error("Conflict with existing reservation")]
     ConflictReservation(ReservationConflictInfo),
 
+    #[error("{} reservations conflict with existing ones", .0.len())]
+    ConflictReservations(Vec<ReservationConflictInfo>),
+
     #[error("Invalid start or end time for the reservation")]
     InvalidTimespan,
 
@@ -22,12 +30,42 @@ pub enum Error {
     #[error("Invalid user id: {0}")]
     InvalidUserId(String),
 
+    #[error("Invalid group id: {0}")]
+    InvalidGroupId(String),
+
     #[error("Invalid resource id: {0}")]
     InvalidResourceId(String),
 
+    #[error("Invalid query cursor: {0}")]
+    InvalidCursor(String),
+
+    #[error("Invalid duration: {0}")]
+    InvalidDuration(String),
+
+    #[error("Invalid recurrence: {0}")]
+    InvalidRecurrence(String),
+
     #[error("No reservation found by given condition")]
     NotFound,
 
+    #[error("Invalid status transition from {from} to {to}")]
+    InvalidTransition {
+        from: ReservationStatus,
+        to: ReservationStatus,
+    },
+
+    #[error("Invalid status: {0}")]
+    InvalidStatus(ReservationStatus),
+
+    #[error("Reason could only be given when cancelling, not when moving to {0}")]
+    InvalidReason(ReservationStatus),
+
+    #[error("Reservation is {0} and could not be changed")]
+    FinalStatus(ReservationStatus),
+
+    #[error("Block overlaps existing reservations: {}", .0.join(", "))]
+    BlockConflict(Vec<String>),
+
     #[error("unknown error")]
     Unknown,
 }
@@ -37,11 +75,24 @@ impl PartialEq for Error {
         match (self, other) {
             (Self::DatabaseError(_), Self::DatabaseError(_)) => true,
             (Self::ConflictReservation(v1), Self::ConflictReservation(v2)) => v1 == v2,
+            (Self::ConflictReservations(v1), Self::ConflictReservations(v2)) => v1 == v2,
             (Self::InvalidReservationId(v1), Self::InvalidReservationId(v2)) => v1 == v2,
+            (Self::InvalidGroupId(v1), Self::InvalidGroupId(v2)) => v1 == v2,
             (Self::InvalidResourceId(v1), Self::InvalidResourceId(v2)) => v1 == v2,
+            (Self::InvalidCursor(v1), Self::InvalidCursor(v2)) => v1 == v2,
+            (Self::InvalidDuration(v1), Self::InvalidDuration(v2)) => v1 == v2,
+            (Self::InvalidRecurrence(v1), Self::InvalidRecurrence(v2)) => v1 == v2,
             (Self::InvalidTimespan, Self::InvalidTimespan) => true,
             (Self::InvalidUserId(v1), Self::InvalidUserId(v2)) => v1 == v2,
             (Self::NotFound, Self::NotFound) => true,
+            (
+                Self::InvalidTransition { from: f1, to: t1 },
+                Self::InvalidTransition { from: f2, to: t2 },
+            ) => f1 == f2 && t1 == t2,
+            (Self::InvalidStatus(v1), Self::InvalidStatus(v2)) => v1 == v2,
+            (Self::InvalidReason(v1), Self::InvalidReason(v2)) => v1 == v2,
+            (Self::FinalStatus(v1), Self::FinalStatus(v2)) => v1 == v2,
+            (Self::BlockConflict(v1), Self::BlockConflict(v2)) => v1 == v2,
             _ => false,
         }
     }
@@ -64,3 +115,125 @@ impl From<sqlx::Error> for Error {
         }
     }
 }
+
+impl From<Error> for Status {
+    fn from(e: Error) -> Self {
+        match e {
+            Error::DatabaseError(_) | Error::Unknown => Status::internal(e.to_string()),
+            Error::ConflictReservation(ReservationConflictInfo::Parsed(ref conflict)) => {
+                let details = ReservationConflictDetails::from(conflict);
+                details::status_with_details(Code::AlreadyExists, e.to_string(), &details)
+            }
+            Error::ConflictReservation(ReservationConflictInfo::Unparsed(ref info)) => {
+                Status::already_exists(format!("{e}: {info}"))
+            }
+            Error::ConflictReservations(ref conflicts) => {
+                let mut details = ReservationConflictsDetails::default();
+                for info in conflicts {
+                    match info {
+                        ReservationConflictInfo::Parsed(conflict) => {
+                            details.conflicts.push(conflict.into())
+                        }
+                        ReservationConflictInfo::Unparsed(info) => {
+                            details.unparsed.push(info.clone())
+                        }
+                    }
+                }
+                details::status_with_details(Code::AlreadyExists, e.to_string(), &details)
+            }
+            Error::InvalidTimespan
+            | Error::InvalidReservationId(_)
+            | Error::InvalidUserId(_)
+            | Error::InvalidGroupId(_)
+            | Error::InvalidResourceId(_)
+            | Error::InvalidCursor(_)
+            | Error::InvalidDuration(_)
+            | Error::InvalidRecurrence(_)
+            | Error::InvalidStatus(_)
+            | Error::InvalidReason(_) => Status::invalid_argument(e.to_string()),
+            Error::BlockConflict(_) => Status::already_exists(e.to_string()),
+            Error::NotFound => Status::not_found(e.to_string()),
+            Error::InvalidTransition { .. } | Error::FinalStatus(_) => {
+                Status::failed_precondition(e.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conflict_error_should_carry_details() {
+        let conflict = ReservationConflict {
+            new: ReservationWindow {
+                rid: "room-114514".to_string(),
+                start: "2025-06-02 19:00:00 UTC".parse().unwrap(),
+                end: "2025-06-05 19:00:00 UTC".parse().unwrap(),
+            },
+            old: ReservationWindow {
+                rid: "room-114514".to_string(),
+                start: "2025-06-01 19:00:00 UTC".parse().unwrap(),
+                end: "2025-06-03 19:00:00 UTC".parse().unwrap(),
+            },
+            buffered: false,
+        };
+        let status: Status =
+            Error::ConflictReservation(ReservationConflictInfo::Parsed(conflict.clone())).into();
+        assert_eq!(status.code(), Code::AlreadyExists);
+
+        // details follow the rich error model, a google.rpc.Status packing them in an Any.
+        let rpc_status = <RpcStatus as prost::Message>::decode(status.details()).unwrap();
+        assert_eq!(rpc_status.code, Code::AlreadyExists as i32);
+        assert_eq!(
+            rpc_status.details[0].type_url,
+            "type.googleapis.com/reservation.ReservationConflictDetails"
+        );
+        let details: ReservationConflictDetails = decode_details(&status).unwrap();
+        assert_eq!(details, ReservationConflictDetails::from(&conflict));
+        let old = details.old.unwrap();
+        assert_eq!(old.resource_id, "room-114514");
+        assert_eq!(old.start.unwrap().seconds, conflict.old.start.timestamp());
+    }
+
+    #[test]
+    fn conflicts_error_should_keep_unparsed_conflicts() {
+        let conflict = "Key (resource_id, timespan)=(room-114514, [\"2025-06-02 19:00:00+00\", \"2025-06-05 19:00:00+00\"]) conflicts with existing key (resource_id, timespan)=(room-114514, [\"2025-06-01 19:00:00+00\", \"2025-06-03 19:00:00+00\"]).";
+        let status: Status = Error::ConflictReservations(vec![
+            conflict.parse().unwrap(),
+            ReservationConflictInfo::Unparsed("unexpected".to_string()),
+        ])
+        .into();
+        assert_eq!(
+            status.message(),
+            "2 reservations conflict with existing ones"
+        );
+
+        let details: ReservationConflictsDetails = decode_details(&status).unwrap();
+        assert_eq!(details.conflicts.len(), 1);
+        assert_eq!(details.unparsed, ["unexpected"]);
+    }
+
+    #[test]
+    fn validation_errors_should_be_invalid_argument() {
+        let status: Status = Error::InvalidTimespan.into();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        let status: Status = Error::InvalidUserId("".to_string()).into();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        let status: Status = Error::InvalidStatus(ReservationStatus::Blocked).into();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        let status: Status = Error::InvalidReason(ReservationStatus::Confirmed).into();
+        assert_eq!(status.code(), Code::InvalidArgument);
+        let status: Status = Error::NotFound.into();
+        assert_eq!(status.code(), Code::NotFound);
+        let status: Status = Error::InvalidTransition {
+            from: ReservationStatus::Confirmed,
+            to: ReservationStatus::Pending,
+        }
+        .into();
+        assert_eq!(status.code(), Code::FailedPrecondition);
+        let status: Status = Error::DatabaseError(sqlx::Error::PoolClosed).into();
+        assert_eq!(status.code(), Code::Internal);
+    }
+}
@@ -3,12 +3,22 @@ mod pb;
 mod types;
 mod utils;
 
-pub use error::{Error, ReservationConflict, ReservationConflictInfo, ReservationWindow};
+pub use error::{
+    Error, ReservationConflict, ReservationConflictInfo, ReservationWindow, RpcStatus,
+    decode_details,
+};
 pub use pb::*;
+pub use types::{
+    ByDay, Frequency, ImportOptions, ImportOutcome, ImportPolicy, ImportReport, QueryCursor,
+    QueryResult, RecurrenceRule, Until,
+};
 pub use utils::*;
 
-// export `prost_types::Timestamp`
-pub use prost_types::Timestamp;
+// export `prost_types::{Duration, Timestamp}`
+pub use prost_types::{Duration, Timestamp};
+
+/// Owner of reservations made by the system rather than a user, e.g. resource blocks. Same as `rsvp.system_user_id()`.
+pub const SYSTEM_USER_ID: &str = "system";
 
 pub trait Validator {
     fn validate(&self) -> Result<(), Error>;
@@ -22,4 +32,16 @@ pub enum RsvpStatus {
     Pending,
     Confirmed,
     Blocked,
+    Cancelled,
+    Rejected,
+}
+
+/// Database representation of reservation update type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "reservation_update_type", rename_all = "UPPERCASE")]
+pub enum RsvpUpdateType {
+    Unknown,
+    Create,
+    Update,
+    Delete,
 }
@@ -18,6 +18,34 @@ pub struct Reservation {
     /// extra
     #[prost(string, tag = "7")]
     pub note: ::prost::alloc::string::String,
+    /// set once the reservation is cancelled.
+    #[prost(message, optional, tag = "8")]
+    pub cancellation: ::core::option::Option<Cancellation>,
+    /// a pending reservation is cancelled once its hold expires. If unset, the hold never expires.
+    #[prost(message, optional, tag = "9")]
+    pub hold_expires_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// set by the server.
+    #[prost(message, optional, tag = "10")]
+    pub created_at: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "11")]
+    pub updated_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// set by the server if the reservation is an occurrence of a recurring reservation.
+    #[prost(string, tag = "12")]
+    pub series_id: ::prost::alloc::string::String,
+    /// set by the server if the reservation is made together with others, see `reserve_many`.
+    #[prost(string, tag = "13")]
+    pub group_id: ::prost::alloc::string::String,
+}
+/// Who cancelled a reservation, when and why.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Cancellation {
+    #[prost(string, tag = "1")]
+    pub cancelled_by: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub cancelled_at: ::core::option::Option<::prost_types::Timestamp>,
+    /// optional reason of the cancellation.
+    #[prost(string, tag = "3")]
+    pub reason: ::prost::alloc::string::String,
 }
 /// To make a reservation(id shuold be empty).
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -31,10 +59,148 @@ pub struct ReserveResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
+/// How a reservation recurs, a subset of RFC 5545.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Recurrence {
+    /// e.g. "FREQ=WEEKLY;BYDAY=MO,WE;COUNT=10". FREQ could be DAILY, WEEKLY or MONTHLY, with optional
+    /// INTERVAL and BYDAY, and either COUNT or UNTIL.
+    #[prost(string, tag = "1")]
+    pub rrule: ::prost::alloc::string::String,
+    /// IANA timezone the rule is expanded in, e.g. "Asia/Shanghai", occurrences keep their local time across DST. Defaults to UTC.
+    #[prost(string, tag = "2")]
+    pub timezone: ::prost::alloc::string::String,
+    /// occurrences starting at these times are skipped.
+    #[prost(message, repeated, tag = "3")]
+    pub exdates: ::prost::alloc::vec::Vec<::prost_types::Timestamp>,
+}
+/// To make a recurring reservation, the reservation is the first occurrence(id should be empty).
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveSeriesRequest {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+    #[prost(message, optional, tag = "2")]
+    pub recurrence: ::core::option::Option<Recurrence>,
+}
+/// Created occurrences will be returned.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveSeriesResponse {
+    #[prost(string, tag = "1")]
+    pub series_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To update occurrences of a recurring reservation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateSeriesRequest {
+    /// id of an occurrence of the series.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(enumeration = "SeriesScope", tag = "2")]
+    pub scope: i32,
+    /// new time of the given occurrence, other occurrences in scope are moved by the same offset and get the same length.
+    /// If unset, times are kept.
+    #[prost(message, optional, tag = "3")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "4")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// new note of the occurrences in scope. If unset, notes are kept.
+    #[prost(string, optional, tag = "5")]
+    pub note: ::core::option::Option<::prost::alloc::string::String>,
+}
+/// Updated occurrences will be returned.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UpdateSeriesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To cancel occurrences of a recurring reservation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelSeriesRequest {
+    /// id of an occurrence of the series.
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(enumeration = "SeriesScope", tag = "2")]
+    pub scope: i32,
+    #[prost(string, tag = "3")]
+    pub cancelled_by: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub reason: ::prost::alloc::string::String,
+}
+/// Cancelled occurrences will be returned, those already cancelled or rejected are skipped.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelSeriesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To make several reservations together, or none of them(ids should be empty).
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveManyRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// Created reservations will be returned in the order of the request.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReserveManyResponse {
+    #[prost(string, tag = "1")]
+    pub group_id: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "2")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To confirm every reservation of a group.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConfirmGroupRequest {
+    #[prost(string, tag = "1")]
+    pub group_id: ::prost::alloc::string::String,
+}
+/// Reservations of the group will be returned.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConfirmGroupResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To cancel every reservation of a group.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelGroupRequest {
+    #[prost(string, tag = "1")]
+    pub group_id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub cancelled_by: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub reason: ::prost::alloc::string::String,
+}
+/// Cancelled reservations will be returned, those already cancelled or rejected are skipped.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CancelGroupResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To move every reservation matching the query to a status, e.g. cancel them while a building is closed.
+/// Paging of the query is ignored. With dry_run set nothing is changed. Only CONFIRMED, REJECTED and
+/// CANCELLED could be set, resources are blocked with `block`.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkTransitionRequest {
+    #[prost(message, optional, tag = "1")]
+    pub query: ::core::option::Option<ReservationQuery>,
+    #[prost(enumeration = "ReservationStatus", tag = "2")]
+    pub status: i32,
+    /// recorded as the reason of the cancellation, could only be given when moving to CANCELLED.
+    #[prost(string, tag = "3")]
+    pub reason: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub dry_run: bool,
+}
+/// Reservations moved to the status will be returned, those which could not move to it are skipped.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkTransitionResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+}
 /// To update a reservation(only note is updatable).
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct UpdateRequest {
     #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
     pub note: ::prost::alloc::string::String,
 }
 /// Updated reservation will be returned.
@@ -43,6 +209,25 @@ pub struct UpdateResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
+/// To move a reservation to another time range and/or resource.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RescheduleRequest {
+    #[prost(string, tag = "1")]
+    pub id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// move the reservation to this resource. If empty, the resource is kept.
+    #[prost(string, tag = "4")]
+    pub resource_id: ::prost::alloc::string::String,
+}
+/// Rescheduled reservation will be returned.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RescheduleResponse {
+    #[prost(message, optional, tag = "1")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
 /// To change reservation status to CONFIRMED.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ConfirmRequest {
@@ -60,6 +245,12 @@ pub struct ConfirmResponse {
 pub struct CancelRequest {
     #[prost(string, tag = "1")]
     pub id: ::prost::alloc::string::String,
+    /// who cancels the reservation.
+    #[prost(string, tag = "2")]
+    pub cancelled_by: ::prost::alloc::string::String,
+    /// optional reason of the cancellation.
+    #[prost(string, tag = "3")]
+    pub reason: ::prost::alloc::string::String,
 }
 /// Cancelled reservation will be returned.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -79,6 +270,105 @@ pub struct GetResponse {
     #[prost(message, optional, tag = "1")]
     pub reservation: ::core::option::Option<Reservation>,
 }
+/// To block a resource for a time range(e.g. maintenance), the block is owned by the system.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockRequest {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// why the resource is blocked, also used as the reason of cancelled reservations.
+    #[prost(string, tag = "4")]
+    pub reason: ::prost::alloc::string::String,
+    #[prost(enumeration = "BlockConflictPolicy", tag = "5")]
+    pub on_conflict: i32,
+}
+/// Created block and the reservations cancelled for it will be returned.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockResponse {
+    #[prost(message, optional, tag = "1")]
+    pub block: ::core::option::Option<Reservation>,
+    #[prost(message, repeated, tag = "2")]
+    pub cancelled: ::prost::alloc::vec::Vec<Reservation>,
+}
+/// To find when a resource is free within a time window.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AvailabilityRequest {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// only return free slots at least this long. If unset, every free slot is returned.
+    #[prost(message, optional, tag = "4")]
+    pub min_duration: ::core::option::Option<::prost_types::Duration>,
+    /// shrink free slots to multiples of the granularity since the unix epoch, e.g. 15 minutes. If unset, slots are not aligned.
+    #[prost(message, optional, tag = "5")]
+    pub granularity: ::core::option::Option<::prost_types::Duration>,
+}
+/// A time range the resource is free.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TimeSlot {
+    #[prost(message, optional, tag = "1")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "2")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Free slots within the window, ordered by start time.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AvailabilityResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub slots: ::prost::alloc::vec::Vec<TimeSlot>,
+}
+/// To set the time kept free before and after every reservation of a resource, e.g. to clean a room.
+/// Changed buffers apply to the reservations which are not over yet, and are rejected if those would conflict.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetBuffersRequest {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    /// if unset, nothing is kept free before a reservation.
+    #[prost(message, optional, tag = "2")]
+    pub before: ::core::option::Option<::prost_types::Duration>,
+    /// if unset, nothing is kept free after a reservation.
+    #[prost(message, optional, tag = "3")]
+    pub after: ::core::option::Option<::prost_types::Duration>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetBuffersResponse {}
+/// To find the earliest slot any of the candidate resources is free, and optionally reserve it.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindSlotRequest {
+    /// candidate resources, earlier ones are preferred if several are free at the same time.
+    #[prost(string, repeated, tag = "1")]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// window to search the slot in.
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+    /// length of the slot.
+    #[prost(message, optional, tag = "4")]
+    pub duration: ::core::option::Option<::prost_types::Duration>,
+    /// only start the slot at multiples of the granularity since the unix epoch. If unset, slots are not aligned.
+    #[prost(message, optional, tag = "5")]
+    pub granularity: ::core::option::Option<::prost_types::Duration>,
+    /// if set, the slot is reserved with the user, note and hold of this reservation, its resource and time are ignored.
+    #[prost(message, optional, tag = "6")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
+/// Found slot, and the reservation made for it if asked to. Nothing is set if no candidate is free.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindSlotResponse {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub slot: ::core::option::Option<TimeSlot>,
+    #[prost(message, optional, tag = "3")]
+    pub reservation: ::core::option::Option<Reservation>,
+}
 /// Query reservations by resource_id, user_id, status, start and end time.
 #[derive(derive_builder::Builder, Clone, PartialEq, ::prost::Message)]
 pub struct ReservationQuery {
@@ -90,16 +380,16 @@ pub struct ReservationQuery {
     #[prost(string, tag = "2")]
     #[builder(setter(into), default)]
     pub user_id: ::prost::alloc::string::String,
-    /// use status to filter results. If UNSPECIFIED, return all reservations.
+    /// use status to filter results. If UNKNOWN and `statuses` is empty, return reservations of any status.
     #[prost(enumeration = "ReservationStatus", tag = "3")]
     #[builder(setter(into), default)]
     pub status: i32,
-    /// start and end time for the reservation query. If 0, use infinite time range.
+    /// start and end time for the reservation query. If unset, the range is unbounded on that side.
     #[prost(message, optional, tag = "4")]
-    #[builder(setter(into, strip_option))]
+    #[builder(setter(into, strip_option), default)]
     pub start: ::core::option::Option<::prost_types::Timestamp>,
     #[prost(message, optional, tag = "5")]
-    #[builder(setter(into, strip_option))]
+    #[builder(setter(into, strip_option), default)]
     pub end: ::core::option::Option<::prost_types::Timestamp>,
     /// current page for query
     #[prost(int32, tag = "6")]
@@ -113,6 +403,26 @@ pub struct ReservationQuery {
     #[prost(bool, tag = "8")]
     #[builder(setter(into), default)]
     pub desc: bool,
+    /// return reservations of any of these statuses, combined with `status` if it is set.
+    #[prost(enumeration = "ReservationStatus", repeated, tag = "9")]
+    #[builder(setter(into), default)]
+    pub statuses: ::prost::alloc::vec::Vec<i32>,
+    /// how reservations are matched against start and end, defaults to OVERLAPS.
+    #[prost(enumeration = "MatchMode", tag = "10")]
+    #[builder(setter(into), default)]
+    pub match_mode: i32,
+    /// continue right after the reservation the cursor was taken from, `page` is ignored if set.
+    #[prost(string, tag = "11")]
+    #[builder(setter(into), default)]
+    pub cursor: ::prost::alloc::string::String,
+    /// count every reservation matching the query, which costs an extra scan.
+    #[prost(bool, tag = "12")]
+    #[builder(setter(into), default)]
+    pub with_total_count: bool,
+    /// field to sort by, defaults to start time. A cursor only continues a query with the same sort.
+    #[prost(enumeration = "SortBy", tag = "13")]
+    #[builder(setter(into), default)]
+    pub sort_by: i32,
 }
 /// / Query request for reservations.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -120,9 +430,43 @@ pub struct QueryRequest {
     #[prost(message, optional, tag = "1")]
     pub query: ::core::option::Option<ReservationQuery>,
 }
+/// One page of reservations matching the query.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct QueryResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub reservations: ::prost::alloc::vec::Vec<Reservation>,
+    /// number of reservations matching the query, only set if `with_total_count` is requested.
+    #[prost(int64, optional, tag = "2")]
+    pub total_count: ::core::option::Option<i64>,
+    /// whether there are more reservations after this page.
+    #[prost(bool, tag = "3")]
+    pub has_more: bool,
+    /// cursor to continue after this page, empty if there are no more reservations.
+    #[prost(string, tag = "4")]
+    pub next_cursor: ::prost::alloc::string::String,
+    /// next page number, 0 if there are no more reservations or the query used a cursor.
+    #[prost(int32, tag = "5")]
+    pub next_page: i32,
+}
 /// Client can watch reservation changes.
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
-pub struct WatchRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct WatchRequest {
+    /// replay every change after this id before streaming live changes. If unset, only stream live changes.
+    #[prost(int64, optional, tag = "1")]
+    pub since_change_id: ::core::option::Option<i64>,
+    /// only watch changes of these resources. Combined with `resource_prefix`, a resource matching either is watched.
+    #[prost(string, repeated, tag = "2")]
+    pub resource_ids: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// only watch changes of resources whose id starts with the prefix.
+    #[prost(string, tag = "3")]
+    pub resource_prefix: ::prost::alloc::string::String,
+    /// only watch changes of this user. If empty, watch all users.
+    #[prost(string, tag = "4")]
+    pub user_id: ::prost::alloc::string::String,
+    /// only watch these kinds of changes. If empty, watch all kinds.
+    #[prost(enumeration = "ReservationUpdateType", repeated, tag = "5")]
+    pub ops: ::prost::alloc::vec::Vec<i32>,
+}
 /// Reservation changes will be streamed to the client.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct WatchResponse {
@@ -130,6 +474,41 @@ pub struct WatchResponse {
     pub op: i32,
     #[prost(message, optional, tag = "2")]
     pub reservation: ::core::option::Option<Reservation>,
+    /// monotonic id of the change, could be used as `since_change_id` to resume watching.
+    #[prost(int64, tag = "3")]
+    pub change_id: i64,
+}
+/// Time window of a reservation involved in a conflict.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ConflictWindow {
+    #[prost(string, tag = "1")]
+    pub resource_id: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub start: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end: ::core::option::Option<::prost_types::Timestamp>,
+}
+/// Error details attached to the grpc status when a reservation conflicts with an existing one.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReservationConflictDetails {
+    /// the reservation window being made.
+    #[prost(message, optional, tag = "1")]
+    pub new: ::core::option::Option<ConflictWindow>,
+    /// the existing reservation window which blocks the new one.
+    #[prost(message, optional, tag = "2")]
+    pub old: ::core::option::Option<ConflictWindow>,
+    /// the windows only overlap with the buffers of the resource, which are included in both windows.
+    #[prost(bool, tag = "3")]
+    pub buffered: bool,
+}
+/// Error details attached to the grpc status when several reservations conflict with existing ones.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ReservationConflictsDetails {
+    #[prost(message, repeated, tag = "1")]
+    pub conflicts: ::prost::alloc::vec::Vec<ReservationConflictDetails>,
+    /// database messages of conflicts which could not be parsed into windows.
+    #[prost(string, repeated, tag = "2")]
+    pub unparsed: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
 }
 /// reservation status for a given time period.
 #[derive(
@@ -141,6 +520,8 @@ pub enum ReservationStatus {
     Pending = 1,
     Confirmed = 2,
     Blocked = 3,
+    Cancelled = 4,
+    Rejected = 5,
 }
 impl ReservationStatus {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -153,6 +534,8 @@ impl ReservationStatus {
             Self::Pending => "RESERVATION_STATUS_PENDING",
             Self::Confirmed => "RESERVATION_STATUS_CONFIRMED",
             Self::Blocked => "RESERVATION_STATUS_BLOCKED",
+            Self::Cancelled => "RESERVATION_STATUS_CANCELLED",
+            Self::Rejected => "RESERVATION_STATUS_REJECTED",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -162,6 +545,8 @@ impl ReservationStatus {
             "RESERVATION_STATUS_PENDING" => Some(Self::Pending),
             "RESERVATION_STATUS_CONFIRMED" => Some(Self::Confirmed),
             "RESERVATION_STATUS_BLOCKED" => Some(Self::Blocked),
+            "RESERVATION_STATUS_CANCELLED" => Some(Self::Cancelled),
+            "RESERVATION_STATUS_REJECTED" => Some(Self::Rejected),
             _ => None,
         }
     }
@@ -199,6 +584,147 @@ impl ReservationUpdateType {
         }
     }
 }
+/// what to do with existing reservations overlapping a new block.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum BlockConflictPolicy {
+    /// refuse to block the resource.
+    Fail = 0,
+    /// cancel the overlapping reservations with the reason of the block.
+    Cancel = 1,
+}
+impl BlockConflictPolicy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Fail => "BLOCK_CONFLICT_POLICY_FAIL",
+            Self::Cancel => "BLOCK_CONFLICT_POLICY_CANCEL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "BLOCK_CONFLICT_POLICY_FAIL" => Some(Self::Fail),
+            "BLOCK_CONFLICT_POLICY_CANCEL" => Some(Self::Cancel),
+            _ => None,
+        }
+    }
+}
+/// how reservations are matched against the time range of a query.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum MatchMode {
+    /// reservation overlaps the time range.
+    Overlaps = 0,
+    /// reservation is within the time range.
+    ContainedBy = 1,
+    /// reservation covers the whole time range.
+    Contains = 2,
+    /// reservation starts within the time range.
+    StartsWithin = 3,
+}
+impl MatchMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Overlaps => "MATCH_MODE_OVERLAPS",
+            Self::ContainedBy => "MATCH_MODE_CONTAINED_BY",
+            Self::Contains => "MATCH_MODE_CONTAINS",
+            Self::StartsWithin => "MATCH_MODE_STARTS_WITHIN",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "MATCH_MODE_OVERLAPS" => Some(Self::Overlaps),
+            "MATCH_MODE_CONTAINED_BY" => Some(Self::ContainedBy),
+            "MATCH_MODE_CONTAINS" => Some(Self::Contains),
+            "MATCH_MODE_STARTS_WITHIN" => Some(Self::StartsWithin),
+            _ => None,
+        }
+    }
+}
+/// which occurrences of a recurring reservation a change applies to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SeriesScope {
+    /// only the given occurrence.
+    Occurrence = 0,
+    /// the given occurrence and every later one.
+    Following = 1,
+    /// every occurrence of the series.
+    All = 2,
+}
+impl SeriesScope {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Occurrence => "SERIES_SCOPE_OCCURRENCE",
+            Self::Following => "SERIES_SCOPE_FOLLOWING",
+            Self::All => "SERIES_SCOPE_ALL",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SERIES_SCOPE_OCCURRENCE" => Some(Self::Occurrence),
+            "SERIES_SCOPE_FOLLOWING" => Some(Self::Following),
+            "SERIES_SCOPE_ALL" => Some(Self::All),
+            _ => None,
+        }
+    }
+}
+/// field reservations are sorted by, ties are always broken by id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum SortBy {
+    Start = 0,
+    End = 1,
+    CreatedAt = 2,
+    UpdatedAt = 3,
+    Duration = 4,
+    UserId = 5,
+    ResourceId = 6,
+}
+impl SortBy {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Start => "SORT_BY_START",
+            Self::End => "SORT_BY_END",
+            Self::CreatedAt => "SORT_BY_CREATED_AT",
+            Self::UpdatedAt => "SORT_BY_UPDATED_AT",
+            Self::Duration => "SORT_BY_DURATION",
+            Self::UserId => "SORT_BY_USER_ID",
+            Self::ResourceId => "SORT_BY_RESOURCE_ID",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "SORT_BY_START" => Some(Self::Start),
+            "SORT_BY_END" => Some(Self::End),
+            "SORT_BY_CREATED_AT" => Some(Self::CreatedAt),
+            "SORT_BY_UPDATED_AT" => Some(Self::UpdatedAt),
+            "SORT_BY_DURATION" => Some(Self::Duration),
+            "SORT_BY_USER_ID" => Some(Self::UserId),
+            "SORT_BY_RESOURCE_ID" => Some(Self::ResourceId),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 pub mod reservation_service_client {
     #![allow(
@@ -305,6 +831,46 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "reserve"));
             self.inner.unary(req, path, codec).await
         }
+        /// Create a recurring reservation.
+        pub async fn reserve_series(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReserveSeriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveSeriesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/reserve_series",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reserve_series",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Create several reservations together, or none of them.
+        pub async fn reserve_many(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ReserveManyRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveManyResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/reserve_many",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reserve_many",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
         /// Update a reservation.
         pub async fn update(
             &mut self,
@@ -321,6 +887,25 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "update"));
             self.inner.unary(req, path, codec).await
         }
+        /// Move a reservation to another time range and/or resource.
+        pub async fn reschedule(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RescheduleRequest>,
+        ) -> std::result::Result<tonic::Response<super::RescheduleResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/reschedule");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "reschedule",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
         /// Confirm a reservation.
         pub async fn confirm(
             &mut self,
@@ -337,6 +922,26 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "confirm"));
             self.inner.unary(req, path, codec).await
         }
+        /// Confirm every reservation of a group.
+        pub async fn confirm_group(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ConfirmGroupRequest>,
+        ) -> std::result::Result<tonic::Response<super::ConfirmGroupResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/confirm_group",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "confirm_group",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
         /// Cancel a reservation.
         pub async fn cancel(
             &mut self,
@@ -353,7 +958,160 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "cancel"));
             self.inner.unary(req, path, codec).await
         }
-        /// Get a reservation by id.
+        /// Cancel every reservation of a group.
+        pub async fn cancel_group(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelGroupRequest>,
+        ) -> std::result::Result<tonic::Response<super::CancelGroupResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/cancel_group",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "cancel_group",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Move every reservation matching a query to a status.
+        pub async fn bulk_transition(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BulkTransitionRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkTransitionResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/bulk_transition",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "bulk_transition",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Update occurrences of a recurring reservation.
+        pub async fn update_series(
+            &mut self,
+            request: impl tonic::IntoRequest<super::UpdateSeriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateSeriesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/update_series",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "update_series",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Cancel occurrences of a recurring reservation.
+        pub async fn cancel_series(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CancelSeriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::CancelSeriesResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/cancel_series",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "cancel_series",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Block a resource for a time range.
+        pub async fn block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::BlockRequest>,
+        ) -> std::result::Result<tonic::Response<super::BlockResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/block");
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("reservation.ReservationService", "block"));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Set the buffers kept free around every reservation of a resource.
+        pub async fn set_buffers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetBuffersRequest>,
+        ) -> std::result::Result<tonic::Response<super::SetBuffersResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/set_buffers");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "set_buffers",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Find the free time slots of a resource within a time window.
+        pub async fn availability(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AvailabilityRequest>,
+        ) -> std::result::Result<tonic::Response<super::AvailabilityResponse>, tonic::Status>
+        {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/reservation.ReservationService/availability",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "availability",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Find the earliest slot any of the candidate resources is free, and optionally reserve it.
+        pub async fn find_slot(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindSlotRequest>,
+        ) -> std::result::Result<tonic::Response<super::FindSlotResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/find_slot");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "find_slot",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
+        /// Get a reservation by id.
         pub async fn get(
             &mut self,
             request: impl tonic::IntoRequest<super::GetRequest>,
@@ -368,7 +1126,7 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "get"));
             self.inner.unary(req, path, codec).await
         }
-        /// Query reservations by resource_id, user_id, status, start and end time.
+        /// Stream every reservation matching the query as it is read, page and page_size are ignored.
         pub async fn query(
             &mut self,
             request: impl tonic::IntoRequest<super::QueryRequest>,
@@ -387,6 +1145,24 @@ pub mod reservation_service_client {
                 .insert(GrpcMethod::new("reservation.ReservationService", "query"));
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Query one page of reservations together with the paging info.
+        pub async fn query_page(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QueryRequest>,
+        ) -> std::result::Result<tonic::Response<super::QueryResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::unknown(format!("Service was not ready: {}", e.into()))
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/reservation.ReservationService/query_page");
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new(
+                "reservation.ReservationService",
+                "query_page",
+            ));
+            self.inner.unary(req, path, codec).await
+        }
         /// another system could watch newly created/confirmed/cancelled reservation.
         pub async fn watch(
             &mut self,
@@ -426,21 +1202,81 @@ pub mod reservation_service_server {
             &self,
             request: tonic::Request<super::ReserveRequest>,
         ) -> std::result::Result<tonic::Response<super::ReserveResponse>, tonic::Status>;
+        /// Create a recurring reservation.
+        async fn reserve_series(
+            &self,
+            request: tonic::Request<super::ReserveSeriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveSeriesResponse>, tonic::Status>;
+        /// Create several reservations together, or none of them.
+        async fn reserve_many(
+            &self,
+            request: tonic::Request<super::ReserveManyRequest>,
+        ) -> std::result::Result<tonic::Response<super::ReserveManyResponse>, tonic::Status>;
         /// Update a reservation.
         async fn update(
             &self,
             request: tonic::Request<super::UpdateRequest>,
         ) -> std::result::Result<tonic::Response<super::UpdateResponse>, tonic::Status>;
+        /// Move a reservation to another time range and/or resource.
+        async fn reschedule(
+            &self,
+            request: tonic::Request<super::RescheduleRequest>,
+        ) -> std::result::Result<tonic::Response<super::RescheduleResponse>, tonic::Status>;
         /// Confirm a reservation.
         async fn confirm(
             &self,
             request: tonic::Request<super::ConfirmRequest>,
         ) -> std::result::Result<tonic::Response<super::ConfirmResponse>, tonic::Status>;
+        /// Confirm every reservation of a group.
+        async fn confirm_group(
+            &self,
+            request: tonic::Request<super::ConfirmGroupRequest>,
+        ) -> std::result::Result<tonic::Response<super::ConfirmGroupResponse>, tonic::Status>;
         /// Cancel a reservation.
         async fn cancel(
             &self,
             request: tonic::Request<super::CancelRequest>,
         ) -> std::result::Result<tonic::Response<super::CancelResponse>, tonic::Status>;
+        /// Cancel every reservation of a group.
+        async fn cancel_group(
+            &self,
+            request: tonic::Request<super::CancelGroupRequest>,
+        ) -> std::result::Result<tonic::Response<super::CancelGroupResponse>, tonic::Status>;
+        /// Move every reservation matching a query to a status.
+        async fn bulk_transition(
+            &self,
+            request: tonic::Request<super::BulkTransitionRequest>,
+        ) -> std::result::Result<tonic::Response<super::BulkTransitionResponse>, tonic::Status>;
+        /// Update occurrences of a recurring reservation.
+        async fn update_series(
+            &self,
+            request: tonic::Request<super::UpdateSeriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::UpdateSeriesResponse>, tonic::Status>;
+        /// Cancel occurrences of a recurring reservation.
+        async fn cancel_series(
+            &self,
+            request: tonic::Request<super::CancelSeriesRequest>,
+        ) -> std::result::Result<tonic::Response<super::CancelSeriesResponse>, tonic::Status>;
+        /// Block a resource for a time range.
+        async fn block(
+            &self,
+            request: tonic::Request<super::BlockRequest>,
+        ) -> std::result::Result<tonic::Response<super::BlockResponse>, tonic::Status>;
+        /// Set the buffers kept free around every reservation of a resource.
+        async fn set_buffers(
+            &self,
+            request: tonic::Request<super::SetBuffersRequest>,
+        ) -> std::result::Result<tonic::Response<super::SetBuffersResponse>, tonic::Status>;
+        /// Find the free time slots of a resource within a time window.
+        async fn availability(
+            &self,
+            request: tonic::Request<super::AvailabilityRequest>,
+        ) -> std::result::Result<tonic::Response<super::AvailabilityResponse>, tonic::Status>;
+        /// Find the earliest slot any of the candidate resources is free, and optionally reserve it.
+        async fn find_slot(
+            &self,
+            request: tonic::Request<super::FindSlotRequest>,
+        ) -> std::result::Result<tonic::Response<super::FindSlotResponse>, tonic::Status>;
         /// Get a reservation by id.
         async fn get(
             &self,
@@ -451,11 +1287,16 @@ pub mod reservation_service_server {
                 Item = std::result::Result<super::Reservation, tonic::Status>,
             > + std::marker::Send
             + 'static;
-        /// Query reservations by resource_id, user_id, status, start and end time.
+        /// Stream every reservation matching the query as it is read, page and page_size are ignored.
         async fn query(
             &self,
             request: tonic::Request<super::QueryRequest>,
         ) -> std::result::Result<tonic::Response<Self::queryStream>, tonic::Status>;
+        /// Query one page of reservations together with the paging info.
+        async fn query_page(
+            &self,
+            request: tonic::Request<super::QueryRequest>,
+        ) -> std::result::Result<tonic::Response<super::QueryResponse>, tonic::Status>;
         /// Server streaming response type for the watch method.
         type watchStream: tonic::codegen::tokio_stream::Stream<
                 Item = std::result::Result<super::WatchResponse, tonic::Status>,
@@ -579,6 +1420,90 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/reservation.ReservationService/reserve_series" => {
+                    #[allow(non_camel_case_types)]
+                    struct reserve_seriesSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ReserveSeriesRequest>
+                        for reserve_seriesSvc<T>
+                    {
+                        type Response = super::ReserveSeriesResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReserveSeriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::reserve_series(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = reserve_seriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/reserve_many" => {
+                    #[allow(non_camel_case_types)]
+                    struct reserve_manySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ReserveManyRequest>
+                        for reserve_manySvc<T>
+                    {
+                        type Response = super::ReserveManyResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ReserveManyRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::reserve_many(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = reserve_manySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/reservation.ReservationService/update" => {
                     #[allow(non_camel_case_types)]
                     struct updateSvc<T: ReservationService>(pub Arc<T>);
@@ -618,6 +1543,47 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/reservation.ReservationService/reschedule" => {
+                    #[allow(non_camel_case_types)]
+                    struct rescheduleSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::RescheduleRequest> for rescheduleSvc<T>
+                    {
+                        type Response = super::RescheduleResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RescheduleRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::reschedule(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = rescheduleSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/reservation.ReservationService/confirm" => {
                     #[allow(non_camel_case_types)]
                     struct confirmSvc<T: ReservationService>(pub Arc<T>);
@@ -657,6 +1623,48 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/reservation.ReservationService/confirm_group" => {
+                    #[allow(non_camel_case_types)]
+                    struct confirm_groupSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::ConfirmGroupRequest>
+                        for confirm_groupSvc<T>
+                    {
+                        type Response = super::ConfirmGroupResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ConfirmGroupRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::confirm_group(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = confirm_groupSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/reservation.ReservationService/cancel" => {
                     #[allow(non_camel_case_types)]
                     struct cancelSvc<T: ReservationService>(pub Arc<T>);
@@ -696,19 +1704,22 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/get" => {
+                "/reservation.ReservationService/cancel_group" => {
                     #[allow(non_camel_case_types)]
-                    struct getSvc<T: ReservationService>(pub Arc<T>);
-                    impl<T: ReservationService> tonic::server::UnaryService<super::GetRequest> for getSvc<T> {
-                        type Response = super::GetResponse;
+                    struct cancel_groupSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::CancelGroupRequest>
+                        for cancel_groupSvc<T>
+                    {
+                        type Response = super::CancelGroupResponse;
                         type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetRequest>,
+                            request: tonic::Request<super::CancelGroupRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::get(&inner, request).await
+                                <T as ReservationService>::cancel_group(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -719,7 +1730,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = getSvc(inner);
+                        let method = cancel_groupSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -735,23 +1746,22 @@ pub mod reservation_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/reservation.ReservationService/query" => {
+                "/reservation.ReservationService/bulk_transition" => {
                     #[allow(non_camel_case_types)]
-                    struct querySvc<T: ReservationService>(pub Arc<T>);
+                    struct bulk_transitionSvc<T: ReservationService>(pub Arc<T>);
                     impl<T: ReservationService>
-                        tonic::server::ServerStreamingService<super::QueryRequest> for querySvc<T>
+                        tonic::server::UnaryService<super::BulkTransitionRequest>
+                        for bulk_transitionSvc<T>
                     {
-                        type Response = super::Reservation;
-                        type ResponseStream = T::queryStream;
-                        type Future =
-                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        type Response = super::BulkTransitionResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::QueryRequest>,
+                            request: tonic::Request<super::BulkTransitionRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as ReservationService>::query(&inner, request).await
+                                <T as ReservationService>::bulk_transition(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -762,7 +1772,7 @@ pub mod reservation_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = querySvc(inner);
+                        let method = bulk_transitionSvc(inner);
                         let codec = tonic::codec::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -773,7 +1783,376 @@ pub mod reservation_service_server {
                                 max_decoding_message_size,
                                 max_encoding_message_size,
                             );
-                        let res = grpc.server_streaming(method, req).await;
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/update_series" => {
+                    #[allow(non_camel_case_types)]
+                    struct update_seriesSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::UpdateSeriesRequest>
+                        for update_seriesSvc<T>
+                    {
+                        type Response = super::UpdateSeriesResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::UpdateSeriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::update_series(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = update_seriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/cancel_series" => {
+                    #[allow(non_camel_case_types)]
+                    struct cancel_seriesSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::CancelSeriesRequest>
+                        for cancel_seriesSvc<T>
+                    {
+                        type Response = super::CancelSeriesResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CancelSeriesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::cancel_series(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = cancel_seriesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/block" => {
+                    #[allow(non_camel_case_types)]
+                    struct blockSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::BlockRequest> for blockSvc<T> {
+                        type Response = super::BlockResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::BlockRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::block(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = blockSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/set_buffers" => {
+                    #[allow(non_camel_case_types)]
+                    struct set_buffersSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::SetBuffersRequest>
+                        for set_buffersSvc<T>
+                    {
+                        type Response = super::SetBuffersResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetBuffersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::set_buffers(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = set_buffersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/availability" => {
+                    #[allow(non_camel_case_types)]
+                    struct availabilitySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::UnaryService<super::AvailabilityRequest>
+                        for availabilitySvc<T>
+                    {
+                        type Response = super::AvailabilityResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AvailabilityRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::availability(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = availabilitySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/find_slot" => {
+                    #[allow(non_camel_case_types)]
+                    struct find_slotSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::FindSlotRequest>
+                        for find_slotSvc<T>
+                    {
+                        type Response = super::FindSlotResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindSlotRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::find_slot(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = find_slotSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/get" => {
+                    #[allow(non_camel_case_types)]
+                    struct getSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::GetRequest> for getSvc<T> {
+                        type Response = super::GetResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::get(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = getSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/query" => {
+                    #[allow(non_camel_case_types)]
+                    struct querySvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService>
+                        tonic::server::ServerStreamingService<super::QueryRequest> for querySvc<T>
+                    {
+                        type Response = super::Reservation;
+                        type ResponseStream = T::queryStream;
+                        type Future =
+                            BoxFuture<tonic::Response<Self::ResponseStream>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::query(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = querySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/reservation.ReservationService/query_page" => {
+                    #[allow(non_camel_case_types)]
+                    struct query_pageSvc<T: ReservationService>(pub Arc<T>);
+                    impl<T: ReservationService> tonic::server::UnaryService<super::QueryRequest> for query_pageSvc<T> {
+                        type Response = super::QueryResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QueryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ReservationService>::query_page(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = query_pageSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
                         Ok(res)
                     };
                     Box::pin(fut)
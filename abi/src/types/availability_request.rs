@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use sqlx::postgres::types::PgRange;
+
+use crate::{
+    AvailabilityRequest, Error, TimeSlot, Validator,
+    types::{get_time_range, to_chrono_duration, vlidate_time_range},
+    utc_time_to_timestamp,
+};
+
+impl AvailabilityRequest {
+    /// Window to search free slots in.
+    pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
+        get_time_range(self.start.as_ref(), self.end.as_ref())
+    }
+
+    /// Shortest free slot to return, zero if unset.
+    pub fn get_min_duration(&self) -> chrono::Duration {
+        self.min_duration
+            .as_ref()
+            .map_or_else(chrono::Duration::zero, to_chrono_duration)
+    }
+
+    /// Granularity free slots are aligned to, `None` if slots are not aligned.
+    pub fn get_granularity(&self) -> Option<chrono::Duration> {
+        self.granularity.as_ref().map(to_chrono_duration)
+    }
+}
+
+impl Validator for AvailabilityRequest {
+    fn validate(&self) -> Result<(), Error> {
+        if self.resource_id.is_empty() {
+            return Err(Error::InvalidResourceId(self.resource_id.clone()));
+        }
+        vlidate_time_range(self.start.as_ref(), self.end.as_ref())?;
+
+        if self.get_min_duration() < chrono::Duration::zero() {
+            return Err(Error::InvalidDuration(format!(
+                "min_duration {} is negative",
+                self.get_min_duration()
+            )));
+        }
+        if let Some(granularity) = self.get_granularity()
+            && granularity <= chrono::Duration::zero()
+        {
+            return Err(Error::InvalidDuration(format!(
+                "granularity {granularity} is not positive"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl TimeSlot {
+    pub fn new(start: DateTime<Utc>, end: DateTime<Utc>) -> Self {
+        Self {
+            start: Some(utc_time_to_timestamp(start)),
+            end: Some(utc_time_to_timestamp(end)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::Duration;
+
+    use super::*;
+
+    #[test]
+    fn availability_request_should_validate() {
+        let mut request = AvailabilityRequest {
+            resource_id: "room-114514".to_string(),
+            start: Some("2025-06-01T00:00:00Z".parse().unwrap()),
+            end: Some("2025-06-02T00:00:00Z".parse().unwrap()),
+            min_duration: Some(Duration {
+                seconds: 1800,
+                nanos: 0,
+            }),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+        assert_eq!(request.get_min_duration(), chrono::Duration::minutes(30));
+        assert_eq!(request.get_granularity(), None);
+
+        request.granularity = Some(Duration::default());
+        assert!(matches!(request.validate(), Err(Error::InvalidDuration(_))));
+
+        request.granularity = None;
+        request.end = None;
+        assert_eq!(request.validate(), Err(Error::InvalidTimespan));
+    }
+}
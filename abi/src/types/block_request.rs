@@ -0,0 +1,60 @@
+use crate::{
+    BlockRequest, Error, Reservation, ReservationStatus, SYSTEM_USER_ID, Validator,
+    types::vlidate_time_range,
+};
+
+impl BlockRequest {
+    /// Creates the blocking reservation, owned by the system principal.
+    pub fn to_reservation(&self) -> Reservation {
+        Reservation {
+            user_id: SYSTEM_USER_ID.to_string(),
+            resource_id: self.resource_id.clone(),
+            status: ReservationStatus::Blocked as i32,
+            start: self.start,
+            end: self.end,
+            note: self.reason.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Validator for BlockRequest {
+    fn validate(&self) -> Result<(), Error> {
+        if self.resource_id.is_empty() {
+            return Err(Error::InvalidResourceId(self.resource_id.clone()));
+        }
+
+        vlidate_time_range(self.start.as_ref(), self.end.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn block_should_be_owned_by_system() {
+        let request = BlockRequest {
+            resource_id: "room-114514".to_string(),
+            start: Some("2025-06-01T00:00:00Z".parse().unwrap()),
+            end: Some("2025-06-02T00:00:00Z".parse().unwrap()),
+            reason: "cleaning".to_string(),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+
+        let block = request.to_reservation();
+        assert_eq!(block.user_id, SYSTEM_USER_ID);
+        assert_eq!(block.status, ReservationStatus::Blocked as i32);
+        assert_eq!(block.note, "cleaning");
+    }
+
+    #[test]
+    fn block_without_resource_should_be_rejected() {
+        let request = BlockRequest::default();
+        assert_eq!(
+            request.validate(),
+            Err(Error::InvalidResourceId("".to_string()))
+        );
+    }
+}
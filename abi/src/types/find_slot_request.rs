@@ -0,0 +1,86 @@
+use chrono::{DateTime, Utc};
+use sqlx::postgres::types::PgRange;
+
+use crate::{
+    Error, FindSlotRequest, Validator,
+    types::{get_time_range, to_chrono_duration, vlidate_time_range},
+};
+
+impl FindSlotRequest {
+    /// Window to search the slot in.
+    pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
+        get_time_range(self.start.as_ref(), self.end.as_ref())
+    }
+
+    /// Length of the slot, zero if unset.
+    pub fn get_duration(&self) -> chrono::Duration {
+        self.duration
+            .as_ref()
+            .map_or_else(chrono::Duration::zero, to_chrono_duration)
+    }
+
+    /// Granularity the slot start is aligned to, `None` if it is not aligned.
+    pub fn get_granularity(&self) -> Option<chrono::Duration> {
+        self.granularity.as_ref().map(to_chrono_duration)
+    }
+}
+
+impl Validator for FindSlotRequest {
+    fn validate(&self) -> Result<(), Error> {
+        if self.resource_ids.is_empty() {
+            return Err(Error::InvalidResourceId("".to_string()));
+        }
+        if let Some(rid) = self.resource_ids.iter().find(|rid| rid.is_empty()) {
+            return Err(Error::InvalidResourceId(rid.clone()));
+        }
+        vlidate_time_range(self.start.as_ref(), self.end.as_ref())?;
+
+        if self.get_duration() <= chrono::Duration::zero() {
+            return Err(Error::InvalidDuration(format!(
+                "duration {} is not positive",
+                self.get_duration()
+            )));
+        }
+        if let Some(granularity) = self.get_granularity()
+            && granularity <= chrono::Duration::zero()
+        {
+            return Err(Error::InvalidDuration(format!(
+                "granularity {granularity} is not positive"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::Duration;
+
+    use super::*;
+
+    #[test]
+    fn find_slot_request_should_validate() {
+        let mut request = FindSlotRequest {
+            resource_ids: vec!["room-1".to_string(), "room-2".to_string()],
+            start: Some("2025-06-01T00:00:00Z".parse().unwrap()),
+            end: Some("2025-06-02T00:00:00Z".parse().unwrap()),
+            duration: Some(Duration {
+                seconds: 3600,
+                nanos: 0,
+            }),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+        assert_eq!(request.get_duration(), chrono::Duration::hours(1));
+
+        request.duration = None;
+        assert!(matches!(request.validate(), Err(Error::InvalidDuration(_))));
+
+        request.resource_ids.clear();
+        assert_eq!(
+            request.validate(),
+            Err(Error::InvalidResourceId("".to_string()))
+        );
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{Error, Reservation, ReservationConflictInfo};
+
+/// How rows failing to import affect the other rows.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ImportPolicy {
+    /// every valid row not conflicting is imported.
+    #[default]
+    PartialSuccess,
+    /// nothing is imported if any row fails.
+    AllOrNothing,
+}
+
+/// Options of a bulk import.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ImportOptions {
+    pub policy: ImportPolicy,
+    /// report what would be imported, then roll everything back.
+    pub dry_run: bool,
+}
+
+/// What happened to one row of a bulk import.
+#[derive(Debug, PartialEq)]
+pub enum ImportOutcome {
+    /// the reservation as stored, or as it would be stored if nothing is committed.
+    Created(Box<Reservation>),
+    Conflict(ReservationConflictInfo),
+    Invalid(Error),
+}
+
+/// Outcome of every row of a bulk import, in the order of the rows.
+#[derive(Debug, Default, PartialEq)]
+pub struct ImportReport {
+    pub rows: Vec<ImportOutcome>,
+    /// whether the created rows are committed, false for a dry run or a failed all-or-nothing import.
+    pub committed: bool,
+}
+
+impl ImportReport {
+    /// Number of rows created.
+    pub fn created(&self) -> usize {
+        self.rows
+            .iter()
+            .filter(|row| matches!(row, ImportOutcome::Created(_)))
+            .count()
+    }
+
+    /// Number of rows conflicting or failing validation.
+    pub fn failed(&self) -> usize {
+        self.rows.len() - self.created()
+    }
+}
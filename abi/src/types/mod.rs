@@ -1,14 +1,33 @@
 use std::ops::Bound;
 
 use chrono::{DateTime, Utc};
-use prost_types::Timestamp;
+use prost_types::{Duration, Timestamp};
 use sqlx::postgres::types::PgRange;
 
 use crate::{Error, timestamp_to_utc_time};
 
+mod availability_request;
+mod block_request;
+mod find_slot_request;
+mod import;
+mod query_cursor;
+mod query_result;
+mod recurrence;
+mod recurrence_rule;
+mod reschedule_request;
 mod reservation;
 mod reservation_query;
 mod reservation_status;
+mod reservation_update_type;
+mod set_buffers_request;
+mod update_series_request;
+mod watch_request;
+mod watch_response;
+
+pub use import::{ImportOptions, ImportOutcome, ImportPolicy, ImportReport};
+pub use query_cursor::QueryCursor;
+pub use query_result::QueryResult;
+pub use recurrence_rule::{ByDay, Frequency, RecurrenceRule, Until};
 
 /// Validates the time range.
 pub fn vlidate_time_range(start: Option<&Timestamp>, end: Option<&Timestamp>) -> Result<(), Error> {
@@ -25,18 +44,25 @@ pub fn vlidate_time_range(start: Option<&Timestamp>, end: Option<&Timestamp>) ->
     Ok(())
 }
 
-/// Get pg datetime range from start and end timestamps.
+/// Converts a protobuf duration to a chrono duration.
+pub(crate) fn to_chrono_duration(duration: &Duration) -> chrono::Duration {
+    chrono::Duration::seconds(duration.seconds)
+        + chrono::Duration::nanoseconds(duration.nanos as i64)
+}
+
+/// Get pg datetime range from start and end timestamps, a missing timestamp is unbounded.
 pub fn get_time_range(
     start: Option<&Timestamp>,
     end: Option<&Timestamp>,
 ) -> PgRange<DateTime<Utc>> {
-    let start = timestamp_to_utc_time(start.unwrap());
-    let end = timestamp_to_utc_time(end.unwrap());
+    let start = start.map_or(Bound::Unbounded, |ts| {
+        Bound::Included(timestamp_to_utc_time(ts))
+    });
+    let end = end.map_or(Bound::Unbounded, |ts| {
+        Bound::Excluded(timestamp_to_utc_time(ts))
+    });
 
-    PgRange {
-        start: Bound::Included(start),
-        end: Bound::Excluded(end),
-    }
+    PgRange { start, end }
 }
 
 #[cfg(test)]
@@ -84,4 +110,19 @@ mod tests {
         assert_eq!(range.start, Bound::Included(timestamp_to_utc_time(&start)));
         assert_eq!(range.end, Bound::Excluded(timestamp_to_utc_time(&end)));
     }
+
+    #[test]
+    fn get_time_range_should_be_unbounded_without_timestamp() {
+        let start = Timestamp {
+            seconds: 1,
+            nanos: 0,
+        };
+        let range = get_time_range(Some(&start), None);
+        assert_eq!(range.start, Bound::Included(timestamp_to_utc_time(&start)));
+        assert_eq!(range.end, Bound::Unbounded);
+
+        let range = get_time_range(None, None);
+        assert_eq!(range.start, Bound::Unbounded);
+        assert_eq!(range.end, Bound::Unbounded);
+    }
 }
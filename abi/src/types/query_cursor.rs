@@ -0,0 +1,134 @@
+use std::str::FromStr;
+
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::SecondsFormat;
+use prost_types::Timestamp;
+use sqlx::types::Uuid;
+
+use crate::{Error, Reservation, SortBy, timestamp_to_utc_time};
+
+/// Position of a reservation in query results, ordered by the sort key and id.
+///
+/// Clients only see it as an opaque string, see `ReservationQuery.cursor`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryCursor {
+    /// sort key of the query the cursor was taken from.
+    pub sort_by: SortBy,
+    /// value of the sort key, timestamps in RFC 3339 and durations in microseconds.
+    pub value: String,
+    pub id: Uuid,
+}
+
+impl QueryCursor {
+    /// Returns the cursor pointing right after the reservation in results sorted by `sort_by`.
+    pub fn after(rsvp: &Reservation, sort_by: SortBy) -> Result<Self, Error> {
+        let id =
+            Uuid::parse_str(&rsvp.id).map_err(|_| Error::InvalidReservationId(rsvp.id.clone()))?;
+        let time = |ts: Option<&Timestamp>| {
+            ts.map(|ts| timestamp_to_utc_time(ts).to_rfc3339_opts(SecondsFormat::Micros, true))
+                .ok_or(Error::InvalidTimespan)
+        };
+        let value = match sort_by {
+            SortBy::Start => time(rsvp.start.as_ref())?,
+            SortBy::End => time(rsvp.end.as_ref())?,
+            SortBy::CreatedAt => time(rsvp.created_at.as_ref())?,
+            SortBy::UpdatedAt => time(rsvp.updated_at.as_ref())?,
+            SortBy::Duration => {
+                let (Some(start), Some(end)) = (rsvp.start.as_ref(), rsvp.end.as_ref()) else {
+                    return Err(Error::InvalidTimespan);
+                };
+                let duration = timestamp_to_utc_time(end) - timestamp_to_utc_time(start);
+                duration
+                    .num_microseconds()
+                    .ok_or(Error::InvalidTimespan)?
+                    .to_string()
+            }
+            SortBy::UserId => rsvp.user_id.clone(),
+            SortBy::ResourceId => rsvp.resource_id.clone(),
+        };
+
+        Ok(Self { sort_by, value, id })
+    }
+
+    /// Encodes the cursor as an url safe string.
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!(
+            "{}|{}|{}",
+            self.sort_by as i32, self.id, self.value
+        ))
+    }
+}
+
+impl FromStr for QueryCursor {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || Error::InvalidCursor(s.to_string());
+        let decoded = URL_SAFE_NO_PAD.decode(s).map_err(|_| err())?;
+        let decoded = String::from_utf8(decoded).map_err(|_| err())?;
+        let mut parts = decoded.splitn(3, '|');
+        let (Some(sort_by), Some(id), Some(value)) = (parts.next(), parts.next(), parts.next())
+        else {
+            return Err(err());
+        };
+        let sort_by = sort_by
+            .parse::<i32>()
+            .ok()
+            .and_then(|v| SortBy::try_from(v).ok())
+            .ok_or_else(err)?;
+        let id = Uuid::parse_str(id).map_err(|_| err())?;
+
+        Ok(Self {
+            sort_by,
+            value: value.to_string(),
+            id,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_should_round_trip() {
+        let rsvp = Reservation {
+            id: "2c4ae3e0-27d9-4d11-a0a4-6a2d3e5c3a10".to_string(),
+            user_id: "kobe|bryant".to_string(),
+            start: Some("2025-06-01T12:00:00.5Z".parse().unwrap()),
+            end: Some("2025-06-01T13:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        let cursor = QueryCursor::after(&rsvp, SortBy::Start).unwrap();
+        assert_eq!(cursor.id.to_string(), rsvp.id);
+        assert_eq!(cursor.value, "2025-06-01T12:00:00.500000Z");
+        assert_eq!(cursor.encode().parse::<QueryCursor>().unwrap(), cursor);
+
+        let cursor = QueryCursor::after(&rsvp, SortBy::Duration).unwrap();
+        assert_eq!(cursor.value, "3599500000");
+        let cursor = QueryCursor::after(&rsvp, SortBy::UserId).unwrap();
+        assert_eq!(cursor.encode().parse::<QueryCursor>().unwrap(), cursor);
+    }
+
+    #[test]
+    fn cursor_without_sort_value_should_fail() {
+        let rsvp = Reservation {
+            id: "2c4ae3e0-27d9-4d11-a0a4-6a2d3e5c3a10".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(
+            QueryCursor::after(&rsvp, SortBy::CreatedAt),
+            Err(Error::InvalidTimespan)
+        );
+    }
+
+    #[test]
+    fn invalid_cursor_should_be_rejected() {
+        for s in ["not a cursor", "MTIzNA", ""] {
+            assert_eq!(
+                s.parse::<QueryCursor>(),
+                Err(Error::InvalidCursor(s.to_string()))
+            );
+        }
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{Error, QueryCursor, QueryResponse, Reservation, ReservationQuery};
+
+/// One page of reservations matching a query.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryResult {
+    pub items: Vec<Reservation>,
+    /// number of reservations matching the query, only counted if requested.
+    pub total_count: Option<i64>,
+    pub has_more: bool,
+    /// cursor to continue after this page, `None` if there are no more reservations.
+    pub next_cursor: Option<QueryCursor>,
+    /// next page number, `None` if there are no more reservations or the query used a cursor.
+    pub next_page: Option<i32>,
+}
+
+impl QueryResult {
+    /// Builds the page from up to `page_size + 1` rows, the extra row only tells there are more.
+    pub fn new(
+        query: &ReservationQuery,
+        mut items: Vec<Reservation>,
+        total_count: Option<i64>,
+    ) -> Result<Self, Error> {
+        let has_more = items.len() > query.get_page_size() as usize;
+        items.truncate(query.get_page_size() as usize);
+
+        let (next_cursor, next_page) = match items.last() {
+            Some(last) if has_more => {
+                let next_page = query.cursor.is_empty().then(|| query.get_page() + 1);
+                (
+                    Some(QueryCursor::after(last, query.get_sort_by())?),
+                    next_page,
+                )
+            }
+            _ => (None, None),
+        };
+
+        Ok(Self {
+            items,
+            total_count,
+            has_more,
+            next_cursor,
+            next_page,
+        })
+    }
+}
+
+impl From<QueryResult> for QueryResponse {
+    fn from(result: QueryResult) -> Self {
+        Self {
+            reservations: result.items,
+            total_count: result.total_count,
+            has_more: result.has_more,
+            next_cursor: result
+                .next_cursor
+                .map(|cursor| cursor.encode())
+                .unwrap_or_default(),
+            next_page: result.next_page.unwrap_or_default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn extra_row_should_mean_more_pages() {
+        let query = ReservationQuery {
+            page: 2,
+            ..Default::default()
+        };
+        let items: Vec<_> = (0..11).map(make_reservation).collect();
+        let result = QueryResult::new(&query, items.clone(), Some(21)).unwrap();
+        assert_eq!(result.items, items[..10]);
+        assert!(result.has_more);
+        assert_eq!(result.next_page, Some(3));
+        assert_eq!(
+            result.next_cursor,
+            Some(QueryCursor::after(&items[9], crate::SortBy::Start).unwrap())
+        );
+
+        let response = QueryResponse::from(result);
+        assert_eq!(response.total_count, Some(21));
+        assert_eq!(response.next_page, 3);
+        assert!(!response.next_cursor.is_empty());
+    }
+
+    #[test]
+    fn last_page_should_have_no_next() {
+        let query = ReservationQuery::default();
+        let items: Vec<_> = (0..3).map(make_reservation).collect();
+        let result = QueryResult::new(&query, items.clone(), None).unwrap();
+        assert_eq!(
+            result,
+            QueryResult {
+                items,
+                ..Default::default()
+            }
+        );
+    }
+
+    fn make_reservation(i: u32) -> Reservation {
+        Reservation {
+            id: format!("2c4ae3e0-27d9-4d11-a0a4-6a2d3e5c3a{i:02}"),
+            start: Some(crate::utc_time_to_timestamp(
+                chrono::DateTime::from_timestamp(1_750_000_000 + i as i64, 0).unwrap(),
+            )),
+            ..Default::default()
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+
+use chrono::Utc;
+use chrono_tz::Tz;
+
+use crate::{
+    Error, Recurrence, RecurrenceRule, Reservation, Validator, timestamp_to_utc_time,
+    utc_time_to_timestamp,
+};
+
+impl Recurrence {
+    /// Timezone the rule is expanded in, UTC if unset.
+    pub fn get_timezone(&self) -> Result<Tz, Error> {
+        if self.timezone.is_empty() {
+            return Ok(Tz::UTC);
+        }
+
+        self.timezone
+            .parse()
+            .map_err(|_| Error::InvalidRecurrence(format!("unknown timezone {}", self.timezone)))
+    }
+
+    /// Expands the reservation into its occurrences, each a copy of it at another time with the same length.
+    pub fn expand(&self, rsvp: &Reservation) -> Result<Vec<Reservation>, Error> {
+        rsvp.validate()?;
+        let rule: RecurrenceRule = self.rrule.parse()?;
+        let tz = self.get_timezone()?;
+        // validated above, both are set.
+        let start = timestamp_to_utc_time(rsvp.start.as_ref().unwrap());
+        let duration = timestamp_to_utc_time(rsvp.end.as_ref().unwrap()) - start;
+        let exdates: HashSet<_> = self.exdates.iter().map(timestamp_to_utc_time).collect();
+
+        let occurrences: Vec<_> = rule
+            .expand(start.with_timezone(&tz))?
+            .into_iter()
+            .map(|start| start.with_timezone(&Utc))
+            .filter(|start| !exdates.contains(start))
+            .map(|start| Reservation {
+                start: Some(utc_time_to_timestamp(start)),
+                end: Some(utc_time_to_timestamp(start + duration)),
+                ..rsvp.clone()
+            })
+            .collect();
+        if occurrences.is_empty() {
+            return Err(Error::InvalidRecurrence("no occurrence".to_string()));
+        }
+
+        Ok(occurrences)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recurrence_should_expand_in_timezone() {
+        let rsvp = Reservation::new_pending(
+            "kobe",
+            "room-114514",
+            "2025-06-02T09:00:00+08:00".parse().unwrap(),
+            "2025-06-02T09:30:00+08:00".parse().unwrap(),
+            "standup",
+        );
+        let recurrence = Recurrence {
+            rrule: "FREQ=WEEKLY;BYDAY=MO,WE;COUNT=4".to_string(),
+            timezone: "Asia/Shanghai".to_string(),
+            exdates: vec!["2025-06-04T01:00:00Z".parse().unwrap()],
+        };
+
+        let occurrences = recurrence.expand(&rsvp).unwrap();
+        let starts: Vec<_> = occurrences
+            .iter()
+            .map(|rsvp| timestamp_to_utc_time(rsvp.start.as_ref().unwrap()).to_rfc3339())
+            .collect();
+        assert_eq!(
+            starts,
+            [
+                "2025-06-02T01:00:00+00:00",
+                "2025-06-09T01:00:00+00:00",
+                "2025-06-11T01:00:00+00:00",
+            ]
+        );
+        assert_eq!(occurrences[1].note, "standup");
+        assert_eq!(
+            occurrences[1].end.unwrap().seconds - occurrences[1].start.unwrap().seconds,
+            1800
+        );
+    }
+
+    #[test]
+    fn unknown_timezone_should_be_rejected() {
+        let recurrence = Recurrence {
+            rrule: "FREQ=DAILY;COUNT=1".to_string(),
+            timezone: "Mars/Olympus_Mons".to_string(),
+            ..Default::default()
+        };
+        assert!(matches!(
+            recurrence.get_timezone(),
+            Err(Error::InvalidRecurrence(_))
+        ));
+    }
+}
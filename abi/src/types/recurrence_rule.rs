@@ -0,0 +1,459 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+use crate::Error;
+
+/// Max number of occurrences a rule could expand into.
+const MAX_OCCURRENCES: usize = 1000;
+/// Max number of periods, e.g. weeks of a weekly rule, searched for occurrences.
+const MAX_PERIODS: u32 = 10_000;
+
+/// How often a rule recurs, `FREQ` of the rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// A weekday of `BYDAY`, e.g. `MO`, or `2MO` / `-1FR` for the second Monday / last Friday of a month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByDay {
+    /// position of the weekday in the month, negative ones count from the end. 0 for every such weekday.
+    pub nth: i8,
+    pub weekday: Weekday,
+}
+
+/// Inclusive bound of the rule, `UNTIL` of the rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Until {
+    /// last local date of an occurrence.
+    Date(NaiveDate),
+    /// latest start of an occurrence.
+    Time(DateTime<Utc>),
+}
+
+/// A RFC 5545 recurrence rule, limited to `FREQ=DAILY|WEEKLY|MONTHLY` with `INTERVAL`, `BYDAY`, and
+/// `COUNT` or `UNTIL`.
+///
+/// Rules must be bounded by `COUNT` or `UNTIL`, so they expand into a finite set of occurrences.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub freq: Frequency,
+    pub interval: u32,
+    pub count: Option<u32>,
+    pub until: Option<Until>,
+    pub by_day: Vec<ByDay>,
+}
+
+impl RecurrenceRule {
+    /// Returns the start of every occurrence from `dtstart` on, in order. Occurrences keep the local
+    /// time of `dtstart` in its timezone.
+    pub fn expand(&self, dtstart: DateTime<Tz>) -> Result<Vec<DateTime<Tz>>, Error> {
+        let tz = dtstart.timezone();
+        let first = dtstart.date_naive();
+        let until = self.until.map(|until| match until {
+            Until::Date(date) => date,
+            Until::Time(time) => time.with_timezone(&tz).date_naive(),
+        });
+
+        let mut starts = Vec::new();
+        for period in 0..MAX_PERIODS {
+            let Some((period_start, days)) = self.period(first, period) else {
+                break;
+            };
+            if until.is_some_and(|until| period_start > until) {
+                break;
+            }
+
+            for day in days {
+                let Some(start) = local_time(&tz, day.and_time(dtstart.time())) else {
+                    continue;
+                };
+                if start < dtstart {
+                    continue;
+                }
+                if self.is_after_until(&start) {
+                    return Ok(starts);
+                }
+
+                // the limit applies to rules bounded by COUNT as well.
+                if starts.len() >= MAX_OCCURRENCES {
+                    return Err(Error::InvalidRecurrence(format!(
+                        "more than {MAX_OCCURRENCES} occurrences"
+                    )));
+                }
+                starts.push(start);
+                if self.count == Some(starts.len() as u32) {
+                    return Ok(starts);
+                }
+            }
+        }
+
+        Ok(starts)
+    }
+
+    /// Returns the first day of the `n`th period from `first` and the days of occurrences in it, in order.
+    fn period(&self, first: NaiveDate, n: u32) -> Option<(NaiveDate, Vec<NaiveDate>)> {
+        let step = n.checked_mul(self.interval)?;
+        let (start, mut days) = match self.freq {
+            Frequency::Daily => {
+                let day = first.checked_add_days(Days::new(step as u64))?;
+                let matched = self.by_day.is_empty()
+                    || self.by_day.iter().any(|by| by.weekday == day.weekday());
+                (day, if matched { vec![day] } else { vec![] })
+            }
+            Frequency::Weekly => {
+                // weeks start on monday.
+                let monday = first
+                    .checked_sub_days(Days::new(first.weekday().num_days_from_monday() as u64))?
+                    .checked_add_days(Days::new(step as u64 * 7))?;
+                let days = if self.by_day.is_empty() {
+                    vec![first.weekday()]
+                } else {
+                    self.by_day.iter().map(|by| by.weekday).collect()
+                };
+                let days = days
+                    .into_iter()
+                    .filter_map(|weekday| {
+                        monday.checked_add_days(Days::new(weekday.num_days_from_monday() as u64))
+                    })
+                    .collect();
+                (monday, days)
+            }
+            Frequency::Monthly => {
+                let month = first.with_day(1)?.checked_add_months(Months::new(step))?;
+                // months without the day of `first`, e.g. the 31st, are skipped.
+                let days = if self.by_day.is_empty() {
+                    month.with_day(first.day()).into_iter().collect()
+                } else {
+                    self.by_day
+                        .iter()
+                        .flat_map(|by| by.days_in(month))
+                        .collect()
+                };
+                (month, days)
+            }
+        };
+        days.sort();
+        days.dedup();
+
+        Some((start, days))
+    }
+
+    fn is_after_until(&self, start: &DateTime<Tz>) -> bool {
+        match self.until {
+            Some(Until::Date(date)) => start.date_naive() > date,
+            Some(Until::Time(time)) => start.with_timezone(&Utc) > time,
+            None => false,
+        }
+    }
+}
+
+impl ByDay {
+    /// Days of the month starting at `month` matching the weekday and position.
+    fn days_in(&self, month: NaiveDate) -> Vec<NaiveDate> {
+        let days: Vec<_> = month
+            .iter_days()
+            .take_while(|day| day.month() == month.month())
+            .filter(|day| day.weekday() == self.weekday)
+            .collect();
+        let index = match self.nth {
+            0 => return days,
+            nth if nth > 0 => nth as usize - 1,
+            nth => match days.len().checked_sub(nth.unsigned_abs() as usize) {
+                Some(index) => index,
+                None => return vec![],
+            },
+        };
+
+        days.get(index).copied().into_iter().collect()
+    }
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = |msg: String| Error::InvalidRecurrence(msg);
+        let mut freq = None;
+        let mut interval = 1;
+        let mut count = None;
+        let mut until = None;
+        let mut by_day = Vec::new();
+
+        let s = s.strip_prefix("RRULE:").unwrap_or(s);
+        for part in s.split(';').filter(|part| !part.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| err(format!("invalid part {part}")))?;
+            let invalid = || err(format!("invalid {key} {value}"));
+            match key {
+                "FREQ" => {
+                    freq = Some(match value {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        _ => return Err(invalid()),
+                    })
+                }
+                "INTERVAL" => {
+                    interval = value
+                        .parse()
+                        .ok()
+                        .filter(|interval| *interval > 0)
+                        .ok_or_else(invalid)?
+                }
+                "COUNT" => {
+                    count = Some(
+                        value
+                            .parse()
+                            .ok()
+                            .filter(|count| *count > 0)
+                            .ok_or_else(invalid)?,
+                    )
+                }
+                "UNTIL" => until = Some(parse_until(value).ok_or_else(invalid)?),
+                "BYDAY" => {
+                    by_day = value
+                        .split(',')
+                        .map(parse_by_day)
+                        .collect::<Option<_>>()
+                        .ok_or_else(invalid)?
+                }
+                _ => return Err(err(format!("unsupported part {key}"))),
+            }
+        }
+
+        let freq = freq.ok_or_else(|| err("missing FREQ".to_string()))?;
+        match (count, until) {
+            (Some(_), Some(_)) => return Err(err("COUNT and UNTIL are exclusive".to_string())),
+            (None, None) => return Err(err("missing COUNT or UNTIL".to_string())),
+            _ => {}
+        }
+        if freq != Frequency::Monthly && by_day.iter().any(|by| by.nth != 0) {
+            return Err(err(
+                "BYDAY positions are only allowed for MONTHLY".to_string()
+            ));
+        }
+
+        Ok(Self {
+            freq,
+            interval,
+            count,
+            until,
+            by_day,
+        })
+    }
+}
+
+/// Resolves a local time in the timezone, times skipped by a DST change are moved an hour later.
+fn local_time(tz: &Tz, time: NaiveDateTime) -> Option<DateTime<Tz>> {
+    tz.from_local_datetime(&time).earliest().or_else(|| {
+        tz.from_local_datetime(&(time + chrono::Duration::hours(1)))
+            .earliest()
+    })
+}
+
+fn parse_until(value: &str) -> Option<Until> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y%m%d") {
+        return Some(Until::Date(date));
+    }
+    // a date-time must be in UTC, as the rule is expanded in the timezone of the reservation.
+    NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ")
+        .ok()
+        .map(|time| Until::Time(time.and_utc()))
+}
+
+fn parse_by_day(value: &str) -> Option<ByDay> {
+    let split = value.len().checked_sub(2)?;
+    let (nth, weekday) = value.split_at_checked(split)?;
+    let weekday = match weekday {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        _ => return None,
+    };
+    let nth = match nth {
+        "" => 0,
+        nth => nth
+            .parse::<i8>()
+            .ok()
+            .filter(|nth| *nth != 0 && (-5..=5).contains(nth))?,
+    };
+
+    Some(ByDay { nth, weekday })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand(rule: &str, dtstart: &str, tz: Tz) -> Vec<String> {
+        let dtstart = tz.from_local_datetime(&dtstart.parse().unwrap()).unwrap();
+        rule.parse::<RecurrenceRule>()
+            .unwrap()
+            .expand(dtstart)
+            .unwrap()
+            .iter()
+            .map(|start| start.to_rfc3339())
+            .collect()
+    }
+
+    #[test]
+    fn rule_should_parse() {
+        let rule: RecurrenceRule =
+            "RRULE:FREQ=MONTHLY;INTERVAL=2;BYDAY=2MO,-1FR;UNTIL=20251231T000000Z"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            rule,
+            RecurrenceRule {
+                freq: Frequency::Monthly,
+                interval: 2,
+                count: None,
+                until: Some(Until::Time("2025-12-31T00:00:00Z".parse().unwrap())),
+                by_day: vec![
+                    ByDay {
+                        nth: 2,
+                        weekday: Weekday::Mon
+                    },
+                    ByDay {
+                        nth: -1,
+                        weekday: Weekday::Fri
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn invalid_rule_should_be_rejected() {
+        for rule in [
+            "FREQ=YEARLY;COUNT=1",
+            "FREQ=DAILY",
+            "FREQ=DAILY;COUNT=1;UNTIL=20250101",
+            "FREQ=WEEKLY;BYDAY=1MO;COUNT=1",
+            "FREQ=DAILY;INTERVAL=0;COUNT=1",
+            "FREQ=DAILY;BYMONTH=1;COUNT=1",
+            "COUNT=1",
+        ] {
+            assert!(
+                matches!(
+                    rule.parse::<RecurrenceRule>(),
+                    Err(Error::InvalidRecurrence(_))
+                ),
+                "{rule}"
+            );
+        }
+    }
+
+    #[test]
+    fn daily_rule_should_expand() {
+        assert_eq!(
+            expand(
+                "FREQ=DAILY;INTERVAL=2;COUNT=3",
+                "2025-06-01T09:00:00",
+                Tz::UTC
+            ),
+            [
+                "2025-06-01T09:00:00+00:00",
+                "2025-06-03T09:00:00+00:00",
+                "2025-06-05T09:00:00+00:00",
+            ]
+        );
+        // weekdays only, until the date.
+        assert_eq!(
+            expand(
+                "FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR;UNTIL=20250610",
+                "2025-06-06T09:00:00",
+                Tz::UTC
+            ),
+            [
+                "2025-06-06T09:00:00+00:00",
+                "2025-06-09T09:00:00+00:00",
+                "2025-06-10T09:00:00+00:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn weekly_rule_should_keep_local_time_across_dst() {
+        // DST of New York ends on 2025-11-02.
+        assert_eq!(
+            expand(
+                "FREQ=WEEKLY;BYDAY=TU,TH;COUNT=4",
+                "2025-10-28T09:30:00",
+                Tz::America__New_York
+            ),
+            [
+                "2025-10-28T09:30:00-04:00",
+                "2025-10-30T09:30:00-04:00",
+                "2025-11-04T09:30:00-05:00",
+                "2025-11-06T09:30:00-05:00",
+            ]
+        );
+        // days before the start in the first week are skipped.
+        assert_eq!(
+            expand(
+                "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,FR;COUNT=3",
+                "2025-06-04T09:00:00",
+                Tz::UTC
+            ),
+            [
+                "2025-06-06T09:00:00+00:00",
+                "2025-06-16T09:00:00+00:00",
+                "2025-06-20T09:00:00+00:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn monthly_rule_should_expand() {
+        // months without a 31st are skipped.
+        assert_eq!(
+            expand("FREQ=MONTHLY;COUNT=3", "2025-01-31T10:00:00", Tz::UTC),
+            [
+                "2025-01-31T10:00:00+00:00",
+                "2025-03-31T10:00:00+00:00",
+                "2025-05-31T10:00:00+00:00",
+            ]
+        );
+        assert_eq!(
+            expand(
+                "FREQ=MONTHLY;BYDAY=-1FR;UNTIL=20250831T000000Z",
+                "2025-06-01T10:00:00",
+                Tz::UTC
+            ),
+            [
+                "2025-06-27T10:00:00+00:00",
+                "2025-07-25T10:00:00+00:00",
+                "2025-08-29T10:00:00+00:00",
+            ]
+        );
+    }
+
+    #[test]
+    fn unbounded_expansion_should_be_rejected() {
+        let rule: RecurrenceRule = "FREQ=DAILY;UNTIL=21000101".parse().unwrap();
+        let dtstart = Tz::UTC.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap();
+        assert!(matches!(
+            rule.expand(dtstart),
+            Err(Error::InvalidRecurrence(_))
+        ));
+
+        let rule: RecurrenceRule = "FREQ=DAILY;COUNT=1000".parse().unwrap();
+        assert_eq!(rule.expand(dtstart).unwrap().len(), MAX_OCCURRENCES);
+        let rule: RecurrenceRule = "FREQ=DAILY;COUNT=1001".parse().unwrap();
+        assert!(matches!(
+            rule.expand(dtstart),
+            Err(Error::InvalidRecurrence(_))
+        ));
+    }
+}
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use sqlx::postgres::types::PgRange;
+
+use crate::{
+    Error, RescheduleRequest, Validator,
+    types::{get_time_range, vlidate_time_range},
+};
+
+impl RescheduleRequest {
+    /// New time range of the reservation.
+    pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
+        get_time_range(self.start.as_ref(), self.end.as_ref())
+    }
+}
+
+impl Validator for RescheduleRequest {
+    fn validate(&self) -> Result<(), Error> {
+        if self.id.is_empty() {
+            return Err(Error::InvalidReservationId(self.id.clone()));
+        }
+
+        vlidate_time_range(self.start.as_ref(), self.end.as_ref())
+    }
+}
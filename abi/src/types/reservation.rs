@@ -8,7 +8,7 @@ use sqlx::{
 };
 
 use crate::{
-    Error, Reservation, ReservationStatus, RsvpStatus, Validator,
+    Cancellation, Error, Reservation, ReservationStatus, RsvpStatus, Validator,
     types::{get_time_range, vlidate_time_range},
     utils::utc_time_to_timestamp,
 };
@@ -30,9 +30,21 @@ impl Reservation {
             end: Some(utc_time_to_timestamp(end.with_timezone(&Utc))),
             note: note.into(),
             status: ReservationStatus::Pending as i32,
+            cancellation: None,
+            hold_expires_at: None,
+            created_at: None,
+            updated_at: None,
+            series_id: "".to_string(),
+            group_id: "".to_string(),
         }
     }
 
+    /// Releases the resource at `expires_at` if the reservation is still pending by then.
+    pub fn with_hold_expires_at(mut self, expires_at: DateTime<FixedOffset>) -> Self {
+        self.hold_expires_at = Some(utc_time_to_timestamp(expires_at.with_timezone(&Utc)));
+        self
+    }
+
     pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
         get_time_range(self.start.as_ref(), self.end.as_ref())
     }
@@ -64,6 +76,23 @@ impl FromRow<'_, PgRow> for Reservation {
         assert!(range.end.is_some());
         let start = range.start.unwrap();
         let end = range.end.unwrap();
+        let cancelled_at: Option<DateTime<Utc>> = row.get("cancelled_at");
+        let cancellation = cancelled_at.map(|at| Cancellation {
+            cancelled_by: row
+                .get::<Option<String>, _>("cancelled_by")
+                .unwrap_or_default(),
+            cancelled_at: Some(utc_time_to_timestamp(at)),
+            reason: row
+                .get::<Option<String>, _>("cancel_reason")
+                .unwrap_or_default(),
+        });
+
+        let hold_expires_at: Option<DateTime<Utc>> = row.get("hold_expires_at");
+        // change snapshots recorded before these were tracked do not have them.
+        let created_at: Option<DateTime<Utc>> = row.get("created_at");
+        let updated_at: Option<DateTime<Utc>> = row.get("updated_at");
+        let series_id: Option<Uuid> = row.get("series_id");
+        let group_id: Option<Uuid> = row.get("group_id");
 
         Ok(Self {
             id: id.to_string(),
@@ -73,6 +102,12 @@ impl FromRow<'_, PgRow> for Reservation {
             start: Some(utc_time_to_timestamp(start)),
             end: Some(utc_time_to_timestamp(end)),
             note: row.get("note"),
+            cancellation,
+            hold_expires_at: hold_expires_at.map(utc_time_to_timestamp),
+            created_at: created_at.map(utc_time_to_timestamp),
+            updated_at: updated_at.map(utc_time_to_timestamp),
+            series_id: series_id.map(|id| id.to_string()).unwrap_or_default(),
+            group_id: group_id.map(|id| id.to_string()).unwrap_or_default(),
         })
     }
 }
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use sqlx::postgres::types::PgRange;
 
 use crate::{
-    Error, ReservationQuery, Validator,
+    Error, QueryCursor, ReservationQuery, ReservationStatus, SortBy, Validator,
     types::{get_time_range, vlidate_time_range},
 };
 
@@ -11,12 +11,200 @@ impl ReservationQuery {
     pub fn get_timespan(&self) -> PgRange<DateTime<Utc>> {
         get_time_range(self.start.as_ref(), self.end.as_ref())
     }
+
+    /// Returns the field to sort by, defaults to the start time.
+    pub fn get_sort_by(&self) -> SortBy {
+        SortBy::try_from(self.sort_by).unwrap_or_default()
+    }
+
+    /// Returns the decoded cursor, or `None` if the query starts from the first page.
+    pub fn get_cursor(&self) -> Result<Option<QueryCursor>, Error> {
+        if self.cursor.is_empty() {
+            return Ok(None);
+        }
+
+        let cursor: QueryCursor = self.cursor.parse()?;
+        // the position of a cursor only makes sense in results of the same sort.
+        if cursor.sort_by != self.get_sort_by() {
+            return Err(Error::InvalidCursor(self.cursor.clone()));
+        }
+        Ok(Some(cursor))
+    }
+
+    /// Returns the page number, starting from 1.
+    pub fn get_page(&self) -> i32 {
+        self.page.max(1)
+    }
+
+    /// Returns the page size, which can not be less than 10 or greater than 100.
+    pub fn get_page_size(&self) -> i32 {
+        if (10..=100).contains(&self.page_size) {
+            self.page_size
+        } else {
+            10
+        }
+    }
+
+    /// Returns the number of rows to skip, a cursor query always starts right after the cursor.
+    pub fn get_offset(&self) -> i32 {
+        if self.cursor.is_empty() {
+            (self.get_page() - 1) * self.get_page_size()
+        } else {
+            0
+        }
+    }
+
+    /// Returns the statuses to filter by, or `None` if reservations of any status are queried.
+    pub fn get_statuses(&self) -> Result<Option<Vec<String>>, Error> {
+        let mut statuses = Vec::new();
+        for status in std::iter::once(self.status).chain(self.statuses.iter().copied()) {
+            let status = ReservationStatus::try_from(status)
+                .map_err(|_| Error::InvalidStatus(ReservationStatus::Unknown))?;
+            if status != ReservationStatus::Unknown {
+                statuses.push(status.to_string());
+            }
+        }
+        statuses.sort();
+        statuses.dedup();
+
+        if statuses.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(statuses))
+        }
+    }
 }
 
 impl Validator for ReservationQuery {
     fn validate(&self) -> Result<(), Error> {
-        vlidate_time_range(self.start.as_ref(), self.end.as_ref())?;
+        // start and end are optional, but must be in order if both are set.
+        if let (Some(start), Some(end)) = (self.start.as_ref(), self.end.as_ref()) {
+            vlidate_time_range(Some(start), Some(end))?;
+        }
+        self.get_cursor()?;
+        self.get_statuses()?;
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ReservationQueryBuilder;
+
+    #[test]
+    fn unknown_status_should_query_any_status() {
+        let query = ReservationQueryBuilder::default()
+            .status(ReservationStatus::Unknown as i32)
+            .start(
+                "2025-06-01T00:00:00Z"
+                    .parse::<prost_types::Timestamp>()
+                    .unwrap(),
+            )
+            .end(
+                "2025-06-04T00:00:00Z"
+                    .parse::<prost_types::Timestamp>()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(query.get_statuses(), Ok(None));
+    }
+
+    #[test]
+    fn open_ended_query_should_be_valid() {
+        let query = ReservationQueryBuilder::default()
+            .start(
+                "2025-06-01T00:00:00Z"
+                    .parse::<prost_types::Timestamp>()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        assert!(query.validate().is_ok());
+        assert!(ReservationQuery::default().validate().is_ok());
+
+        let query = ReservationQueryBuilder::default()
+            .start(
+                "2025-06-04T00:00:00Z"
+                    .parse::<prost_types::Timestamp>()
+                    .unwrap(),
+            )
+            .end(
+                "2025-06-01T00:00:00Z"
+                    .parse::<prost_types::Timestamp>()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(query.validate(), Err(Error::InvalidTimespan));
+    }
+
+    #[test]
+    fn paging_should_be_normalized() {
+        let query = ReservationQuery {
+            page: 0,
+            page_size: 1000,
+            ..Default::default()
+        };
+        assert_eq!(query.get_page(), 1);
+        assert_eq!(query.get_page_size(), 10);
+        assert_eq!(query.get_offset(), 0);
+
+        let query = ReservationQuery {
+            page: 3,
+            page_size: 20,
+            ..Default::default()
+        };
+        assert_eq!(query.get_offset(), 40);
+        let query = ReservationQuery {
+            cursor: "cursor".to_string(),
+            ..query
+        };
+        assert_eq!(query.get_offset(), 0);
+    }
+
+    #[test]
+    fn statuses_should_be_combined_with_status() {
+        let query = ReservationQueryBuilder::default()
+            .status(ReservationStatus::Pending as i32)
+            .statuses(vec![
+                ReservationStatus::Confirmed as i32,
+                ReservationStatus::Pending as i32,
+                ReservationStatus::Unknown as i32,
+            ])
+            .start(
+                "2025-06-01T00:00:00Z"
+                    .parse::<prost_types::Timestamp>()
+                    .unwrap(),
+            )
+            .end(
+                "2025-06-04T00:00:00Z"
+                    .parse::<prost_types::Timestamp>()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+        assert_eq!(
+            query.get_statuses(),
+            Ok(Some(vec!["CONFIRMED".to_string(), "PENDING".to_string()]))
+        );
+    }
+
+    #[test]
+    fn invalid_status_should_be_rejected() {
+        let query = ReservationQuery {
+            statuses: vec![42],
+            ..Default::default()
+        };
+        assert_eq!(
+            query.get_statuses(),
+            Err(Error::InvalidStatus(ReservationStatus::Unknown))
+        );
+        assert_eq!(
+            query.validate(),
+            Err(Error::InvalidStatus(ReservationStatus::Unknown))
+        );
+    }
+}
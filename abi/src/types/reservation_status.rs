@@ -1,6 +1,46 @@
 use std::fmt;
 
-use crate::{ReservationStatus, RsvpStatus};
+use crate::{Error, ReservationStatus, RsvpStatus};
+
+impl ReservationStatus {
+    /// Checks whether a reservation could move from this status to `to`.
+    ///
+    /// - PENDING could be confirmed, rejected or cancelled.
+    /// - CONFIRMED could be cancelled.
+    /// - BLOCKED could be cancelled to release the resource.
+    /// - any other status could be turned into BLOCKED by an admin.
+    /// - CANCELLED and REJECTED are final.
+    pub fn can_transition_to(self, to: ReservationStatus) -> bool {
+        use ReservationStatus::*;
+
+        match (self, to) {
+            (Unknown, _) | (_, Unknown) => false,
+            (Cancelled | Rejected, _) => false,
+            (Blocked, Blocked) => false,
+            (_, Blocked) => true,
+            (Pending, Confirmed | Rejected | Cancelled) => true,
+            (Confirmed | Blocked, Cancelled) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the reservation is over, i.e. CANCELLED or REJECTED, and no longer holds the resource.
+    pub fn is_final(self) -> bool {
+        matches!(
+            self,
+            ReservationStatus::Cancelled | ReservationStatus::Rejected
+        )
+    }
+
+    /// Returns the target status if the transition is legal.
+    pub fn transition_to(self, to: ReservationStatus) -> Result<ReservationStatus, Error> {
+        if self.can_transition_to(to) {
+            Ok(to)
+        } else {
+            Err(Error::InvalidTransition { from: self, to })
+        }
+    }
+}
 
 impl From<RsvpStatus> for ReservationStatus {
     fn from(value: RsvpStatus) -> Self {
@@ -9,6 +49,8 @@ impl From<RsvpStatus> for ReservationStatus {
             RsvpStatus::Pending => ReservationStatus::Pending,
             RsvpStatus::Confirmed => ReservationStatus::Confirmed,
             RsvpStatus::Blocked => ReservationStatus::Blocked,
+            RsvpStatus::Cancelled => ReservationStatus::Cancelled,
+            RsvpStatus::Rejected => ReservationStatus::Rejected,
         }
     }
 }
@@ -17,9 +59,56 @@ impl fmt::Display for ReservationStatus {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             ReservationStatus::Blocked => write!(f, "BLOCKED"),
+            ReservationStatus::Cancelled => write!(f, "CANCELLED"),
             ReservationStatus::Confirmed => write!(f, "CONFIRMED"),
             ReservationStatus::Pending => write!(f, "PENDING"),
+            ReservationStatus::Rejected => write!(f, "REJECTED"),
             ReservationStatus::Unknown => write!(f, "UNKNOWN"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_transitions_should_work() {
+        let pending = ReservationStatus::Pending;
+        assert!(pending.can_transition_to(ReservationStatus::Confirmed));
+        assert!(pending.can_transition_to(ReservationStatus::Rejected));
+        assert!(pending.can_transition_to(ReservationStatus::Cancelled));
+        assert!(pending.can_transition_to(ReservationStatus::Blocked));
+        assert!(!pending.can_transition_to(ReservationStatus::Pending));
+        assert!(!pending.is_final());
+    }
+
+    #[test]
+    fn final_status_should_stay_final() {
+        for status in [ReservationStatus::Cancelled, ReservationStatus::Rejected] {
+            assert!(status.is_final());
+            assert!(!status.can_transition_to(ReservationStatus::Pending));
+            assert!(!status.can_transition_to(ReservationStatus::Confirmed));
+            assert!(!status.can_transition_to(ReservationStatus::Cancelled));
+            assert!(!status.can_transition_to(ReservationStatus::Blocked));
+        }
+    }
+
+    #[test]
+    fn illegal_transition_should_return_error() {
+        let err = ReservationStatus::Confirmed
+            .transition_to(ReservationStatus::Pending)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            Error::InvalidTransition {
+                from: ReservationStatus::Confirmed,
+                to: ReservationStatus::Pending,
+            }
+        );
+        assert_eq!(
+            ReservationStatus::Blocked.transition_to(ReservationStatus::Cancelled),
+            Ok(ReservationStatus::Cancelled)
+        );
+    }
+}
@@ -0,0 +1,12 @@
+use crate::{ReservationUpdateType, RsvpUpdateType};
+
+impl From<RsvpUpdateType> for ReservationUpdateType {
+    fn from(value: RsvpUpdateType) -> Self {
+        match value {
+            RsvpUpdateType::Unknown => ReservationUpdateType::Unknown,
+            RsvpUpdateType::Create => ReservationUpdateType::Create,
+            RsvpUpdateType::Update => ReservationUpdateType::Update,
+            RsvpUpdateType::Delete => ReservationUpdateType::Delete,
+        }
+    }
+}
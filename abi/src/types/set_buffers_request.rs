@@ -0,0 +1,69 @@
+use crate::{Error, SetBuffersRequest, Validator, types::to_chrono_duration};
+
+impl SetBuffersRequest {
+    /// Time kept free before every reservation, zero if unset.
+    pub fn get_before(&self) -> chrono::Duration {
+        self.before
+            .as_ref()
+            .map_or_else(chrono::Duration::zero, to_chrono_duration)
+    }
+
+    /// Time kept free after every reservation, zero if unset.
+    pub fn get_after(&self) -> chrono::Duration {
+        self.after
+            .as_ref()
+            .map_or_else(chrono::Duration::zero, to_chrono_duration)
+    }
+}
+
+impl Validator for SetBuffersRequest {
+    fn validate(&self) -> Result<(), Error> {
+        if self.resource_id.is_empty() {
+            return Err(Error::InvalidResourceId(self.resource_id.clone()));
+        }
+        for (name, buffer) in [("before", self.get_before()), ("after", self.get_after())] {
+            if buffer < chrono::Duration::zero() {
+                return Err(Error::InvalidDuration(format!(
+                    "{name} buffer {buffer} is negative"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::Duration;
+
+    use super::*;
+
+    #[test]
+    fn set_buffers_request_should_validate() {
+        let mut request = SetBuffersRequest {
+            resource_id: "room-114514".to_string(),
+            after: Some(Duration {
+                seconds: 900,
+                nanos: 0,
+            }),
+            ..Default::default()
+        };
+        assert!(request.validate().is_ok());
+        assert_eq!(request.get_before(), chrono::Duration::zero());
+        assert_eq!(request.get_after(), chrono::Duration::minutes(15));
+
+        request.before = Some(Duration {
+            seconds: -1,
+            nanos: 0,
+        });
+        assert!(matches!(request.validate(), Err(Error::InvalidDuration(_))));
+
+        request.before = None;
+        request.resource_id.clear();
+        assert_eq!(
+            request.validate(),
+            Err(Error::InvalidResourceId("".to_string()))
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use crate::{Error, UpdateSeriesRequest, Validator, types::vlidate_time_range};
+
+impl Validator for UpdateSeriesRequest {
+    fn validate(&self) -> Result<(), Error> {
+        if self.id.is_empty() {
+            return Err(Error::InvalidReservationId(self.id.clone()));
+        }
+
+        // times are either kept or both changed.
+        match (self.start.as_ref(), self.end.as_ref()) {
+            (None, None) => Ok(()),
+            (start, end) => vlidate_time_range(start, end),
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{WatchRequest, WatchResponse};
+
+impl WatchRequest {
+    /// Checks whether the change passes every filter of the request.
+    pub fn matches(&self, change: &WatchResponse) -> bool {
+        if !self.ops.is_empty() && !self.ops.contains(&change.op) {
+            return false;
+        }
+
+        let Some(rsvp) = change.reservation.as_ref() else {
+            return false;
+        };
+        if !self.user_id.is_empty() && self.user_id != rsvp.user_id {
+            return false;
+        }
+
+        // a resource matching either the id list or the prefix is watched.
+        if self.resource_ids.is_empty() && self.resource_prefix.is_empty() {
+            return true;
+        }
+        self.resource_ids.contains(&rsvp.resource_id)
+            || (!self.resource_prefix.is_empty()
+                && rsvp.resource_id.starts_with(&self.resource_prefix))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Reservation, ReservationUpdateType};
+
+    use super::*;
+
+    #[test]
+    fn empty_request_should_match_everything() {
+        let req = WatchRequest::default();
+        assert!(req.matches(&change("kobe", "room-101", ReservationUpdateType::Create)));
+        assert!(req.matches(&change("man", "car-1", ReservationUpdateType::Delete)));
+    }
+
+    #[test]
+    fn request_should_filter_by_resource() {
+        let req = WatchRequest {
+            resource_ids: vec!["car-1".to_string()],
+            resource_prefix: "room-1".to_string(),
+            ..Default::default()
+        };
+        assert!(req.matches(&change("kobe", "room-101", ReservationUpdateType::Create)));
+        assert!(req.matches(&change("kobe", "car-1", ReservationUpdateType::Create)));
+        assert!(!req.matches(&change("kobe", "room-201", ReservationUpdateType::Create)));
+        assert!(!req.matches(&change("kobe", "car-2", ReservationUpdateType::Create)));
+    }
+
+    #[test]
+    fn request_should_filter_by_user_and_op() {
+        let req = WatchRequest {
+            user_id: "kobe".to_string(),
+            ops: vec![
+                ReservationUpdateType::Create as i32,
+                ReservationUpdateType::Delete as i32,
+            ],
+            ..Default::default()
+        };
+        assert!(req.matches(&change("kobe", "room-101", ReservationUpdateType::Delete)));
+        assert!(!req.matches(&change("kobe", "room-101", ReservationUpdateType::Update)));
+        assert!(!req.matches(&change("man", "room-101", ReservationUpdateType::Create)));
+    }
+
+    fn change(uid: &str, rid: &str, op: ReservationUpdateType) -> WatchResponse {
+        WatchResponse {
+            op: op as i32,
+            reservation: Some(Reservation {
+                user_id: uid.to_string(),
+                resource_id: rid.to_string(),
+                ..Default::default()
+            }),
+            change_id: 1,
+        }
+    }
+}
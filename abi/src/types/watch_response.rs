@@ -0,0 +1,16 @@
+use sqlx::{FromRow, Row, postgres::PgRow};
+
+use crate::{Reservation, ReservationUpdateType, RsvpUpdateType, WatchResponse};
+
+/// Builds a watch response from a row with `change_id`, `op` and all reservation columns.
+impl FromRow<'_, PgRow> for WatchResponse {
+    fn from_row(row: &'_ PgRow) -> Result<Self, sqlx::Error> {
+        let op: RsvpUpdateType = row.try_get("op")?;
+
+        Ok(Self {
+            op: ReservationUpdateType::from(op) as i32,
+            reservation: Some(Reservation::from_row(row)?),
+            change_id: row.try_get("change_id")?,
+        })
+    }
+}
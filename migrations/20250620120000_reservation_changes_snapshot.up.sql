@@ -0,0 +1,26 @@
+-- keep a snapshot of the reservation for every change, so deleted reservations can still be sent to watchers.
+ALTER TABLE rsvp.reservations_changes ADD CONSTRAINT reservations_changes_pkey PRIMARY KEY (id);
+ALTER TABLE rsvp.reservations_changes ADD COLUMN reservation jsonb;
+UPDATE rsvp.reservations_changes c SET reservation = to_jsonb(r) FROM rsvp.reservations r WHERE r.id = c.reservation_id;
+-- changes of reservations that no longer exist can not be restored, they are kept but skipped by watchers.
+
+CREATE OR REPLACE FUNCTION rsvp.reservation_trigger() RETURNS trigger AS
+$$
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        -- update reservations_changes
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'CREATE', to_jsonb(NEW));
+    ELSIF TG_OP = 'UPDATE' THEN
+        -- if status changed, update reservations_changes
+        IF OLD.status <> NEW.status THEN
+            INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'UPDATE', to_jsonb(NEW));
+        END IF;
+    ELSIF TG_OP = 'DELETE' THEN
+        -- update reservations_changes
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (OLD.id, 'DELETE', to_jsonb(OLD));
+    END IF;
+    -- notify the reservation change
+    NOTIFY reservation_update;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,23 @@
+CREATE OR REPLACE FUNCTION rsvp.reservation_trigger() RETURNS trigger AS
+$$
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        -- update reservations_changes
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'CREATE', to_jsonb(NEW));
+    ELSIF TG_OP = 'UPDATE' THEN
+        -- if status changed, update reservations_changes
+        IF OLD.status <> NEW.status THEN
+            INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'UPDATE', to_jsonb(NEW));
+        END IF;
+    ELSIF TG_OP = 'DELETE' THEN
+        -- update reservations_changes
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (OLD.id, 'DELETE', to_jsonb(OLD));
+    END IF;
+    -- notify the reservation change
+    NOTIFY reservation_update;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+ALTER SEQUENCE rsvp.reservations_changes_id_seq AS integer;
+ALTER TABLE rsvp.reservations_changes ALTER COLUMN id TYPE integer;
@@ -0,0 +1,31 @@
+ALTER TABLE rsvp.reservations_changes ALTER COLUMN id TYPE bigint;
+ALTER SEQUENCE rsvp.reservations_changes_id_seq AS bigint;
+
+CREATE OR REPLACE FUNCTION rsvp.reservation_trigger() RETURNS trigger AS
+$$
+BEGIN
+    -- only status changes are recorded for updates
+    IF TG_OP = 'UPDATE' AND OLD.status = NEW.status THEN
+        RETURN NULL;
+    END IF;
+
+    -- serialize writers of reservations_changes until commit, so change ids become visible in
+    -- commit order and watchers reading changes by id never skip one. The lock is global: every
+    -- transaction recording a change waits for the others to commit, whatever the resource, so
+    -- change throughput is bounded by a single writer at a time.
+    PERFORM pg_advisory_xact_lock(hashtext('rsvp.reservations_changes'));
+
+    IF TG_OP = 'INSERT' THEN
+        -- update reservations_changes
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'CREATE', to_jsonb(NEW));
+    ELSIF TG_OP = 'UPDATE' THEN
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'UPDATE', to_jsonb(NEW));
+    ELSIF TG_OP = 'DELETE' THEN
+        -- update reservations_changes
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (OLD.id, 'DELETE', to_jsonb(OLD));
+    END IF;
+    -- notify the reservation change
+    NOTIFY reservation_update;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1 @@
+-- postgres can not drop a value from an enum, 'CANCELLED' is left in rsvp.reservation_status.
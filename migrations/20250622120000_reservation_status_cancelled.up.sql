@@ -0,0 +1,2 @@
+-- a new enum value can not be used in the transaction adding it, so it lives in its own migration.
+ALTER TYPE rsvp.reservation_status ADD VALUE 'CANCELLED';
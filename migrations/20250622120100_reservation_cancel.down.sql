@@ -0,0 +1,37 @@
+DROP FUNCTION rsvp.query;
+
+-- cancelled reservations would conflict with the original constraint.
+DELETE FROM rsvp.reservations WHERE status = 'CANCELLED';
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_resource_exclusion;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_resource_exclusion
+    EXCLUDE USING GIST (resource_id WITH =, timespan WITH &&);
+
+ALTER TABLE rsvp.reservations
+    DROP COLUMN cancelled_at,
+    DROP COLUMN cancelled_by,
+    DROP COLUMN cancel_reason;
+
+CREATE FUNCTION rsvp.query(uid text, rid text, during tstzrange, r_status rsvp.reservation_status, page integer default 1, page_size integer default 10, is_desc boolean default false) RETURNS TABLE (LIKE rsvp.reservations) as $$
+BEGIN
+    -- page number can not be less than 1
+    IF page < 1 THEN
+        page := 1;
+    END IF;
+    -- pagr size can not be less than 10 or greater than 100
+    IF page_size < 10 or page_size > 100 THEN
+        page_size := 10;
+    END IF;
+
+    RETURN QUERY
+    SELECT *
+    FROM rsvp.reservations r
+    WHERE (uid IS NULL OR r.user_id = uid)
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND r.status = r_status
+      AND during @> r.timespan
+    ORDER BY
+        CASE WHEN is_desc THEN lower(r.timespan) END DESC,
+        CASE WHEN NOT is_desc THEN lower(r.timespan) END ASC
+    LIMIT page_size OFFSET (page - 1) * page_size;
+END;
+$$ LANGUAGE plpgsql;
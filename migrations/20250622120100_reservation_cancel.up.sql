@@ -0,0 +1,37 @@
+-- who cancelled a reservation, when and why.
+ALTER TABLE rsvp.reservations
+    ADD COLUMN cancelled_at timestamptz,
+    ADD COLUMN cancelled_by VARCHAR(64),
+    ADD COLUMN cancel_reason TEXT;
+
+-- cancelled reservations are kept, but no longer block the resource.
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_resource_exclusion;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_resource_exclusion
+    EXCLUDE USING GIST (resource_id WITH =, timespan WITH &&) WHERE (status <> 'CANCELLED');
+
+-- return the table rowtype instead of a copy of its columns, so the function follows new columns.
+DROP FUNCTION rsvp.query;
+CREATE FUNCTION rsvp.query(uid text, rid text, during tstzrange, r_status rsvp.reservation_status, page integer default 1, page_size integer default 10, is_desc boolean default false) RETURNS SETOF rsvp.reservations as $$
+BEGIN
+    -- page number can not be less than 1
+    IF page < 1 THEN
+        page := 1;
+    END IF;
+    -- pagr size can not be less than 10 or greater than 100
+    IF page_size < 10 or page_size > 100 THEN
+        page_size := 10;
+    END IF;
+
+    RETURN QUERY
+    SELECT *
+    FROM rsvp.reservations r
+    WHERE (uid IS NULL OR r.user_id = uid)
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND r.status = r_status
+      AND during @> r.timespan
+    ORDER BY
+        CASE WHEN is_desc THEN lower(r.timespan) END DESC,
+        CASE WHEN NOT is_desc THEN lower(r.timespan) END ASC
+    LIMIT page_size OFFSET (page - 1) * page_size;
+END;
+$$ LANGUAGE plpgsql;
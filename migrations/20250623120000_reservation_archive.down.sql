@@ -0,0 +1,62 @@
+CREATE OR REPLACE FUNCTION rsvp.reservation_trigger() RETURNS trigger AS
+$$
+BEGIN
+    -- only status changes are recorded for updates
+    IF TG_OP = 'UPDATE' AND OLD.status = NEW.status THEN
+        RETURN NULL;
+    END IF;
+
+    -- serialize writers of reservations_changes until commit, so change ids become visible in
+    -- commit order and watchers reading changes by id never skip one.
+    PERFORM pg_advisory_xact_lock(hashtext('rsvp.reservations_changes'));
+
+    IF TG_OP = 'INSERT' THEN
+        -- update reservations_changes
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'CREATE', to_jsonb(NEW));
+    ELSIF TG_OP = 'UPDATE' THEN
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'UPDATE', to_jsonb(NEW));
+    ELSIF TG_OP = 'DELETE' THEN
+        -- update reservations_changes
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (OLD.id, 'DELETE', to_jsonb(OLD));
+    END IF;
+    -- notify the reservation change
+    NOTIFY reservation_update;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP FUNCTION rsvp.query_archive;
+
+CREATE OR REPLACE FUNCTION rsvp.query(uid text, rid text, during tstzrange, r_status rsvp.reservation_status, page integer default 1, page_size integer default 10, is_desc boolean default false) RETURNS SETOF rsvp.reservations as $$
+BEGIN
+    -- page number can not be less than 1
+    IF page < 1 THEN
+        page := 1;
+    END IF;
+    -- pagr size can not be less than 10 or greater than 100
+    IF page_size < 10 or page_size > 100 THEN
+        page_size := 10;
+    END IF;
+
+    RETURN QUERY
+    SELECT *
+    FROM rsvp.reservations r
+    WHERE (uid IS NULL OR r.user_id = uid)
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND r.status = r_status
+      AND during @> r.timespan
+    ORDER BY
+        CASE WHEN is_desc THEN lower(r.timespan) END DESC,
+        CASE WHEN NOT is_desc THEN lower(r.timespan) END ASC
+    LIMIT page_size OFFSET (page - 1) * page_size;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TABLE rsvp.reservations_archive;
+
+-- deleted reservations are dropped for good.
+DELETE FROM rsvp.reservations WHERE deleted_at IS NOT NULL;
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_resource_exclusion;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_resource_exclusion
+    EXCLUDE USING GIST (resource_id WITH =, timespan WITH &&) WHERE (status <> 'CANCELLED');
+ALTER TABLE rsvp.reservations DROP COLUMN deleted_at;
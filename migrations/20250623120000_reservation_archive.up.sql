@@ -0,0 +1,97 @@
+-- deleted reservations are kept until archived.
+ALTER TABLE rsvp.reservations ADD COLUMN deleted_at timestamptz;
+
+-- deleted reservations no longer block the resource.
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_resource_exclusion;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_resource_exclusion
+    EXCLUDE USING GIST (resource_id WITH =, timespan WITH &&) WHERE (status <> 'CANCELLED' AND deleted_at IS NULL);
+
+-- reservations moved out of rsvp.reservations once they are old enough.
+CREATE TABLE rsvp.reservations_archive (
+    LIKE rsvp.reservations INCLUDING DEFAULTS,
+    archived_at timestamptz NOT NULL DEFAULT now(),
+
+    CONSTRAINT reservations_archive_pkey PRIMARY KEY (id)
+);
+CREATE INDEX reservations_archive_user_id_idx ON rsvp.reservations_archive (user_id);
+CREATE INDEX reservations_archive_resource_id_idx ON rsvp.reservations_archive (resource_id);
+
+CREATE OR REPLACE FUNCTION rsvp.query(uid text, rid text, during tstzrange, r_status rsvp.reservation_status, page integer default 1, page_size integer default 10, is_desc boolean default false) RETURNS SETOF rsvp.reservations as $$
+BEGIN
+    -- page number can not be less than 1
+    IF page < 1 THEN
+        page := 1;
+    END IF;
+    -- pagr size can not be less than 10 or greater than 100
+    IF page_size < 10 or page_size > 100 THEN
+        page_size := 10;
+    END IF;
+
+    RETURN QUERY
+    SELECT *
+    FROM rsvp.reservations r
+    WHERE (uid IS NULL OR r.user_id = uid)
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND r.status = r_status
+      AND during @> r.timespan
+      AND r.deleted_at IS NULL
+    ORDER BY
+        CASE WHEN is_desc THEN lower(r.timespan) END DESC,
+        CASE WHEN NOT is_desc THEN lower(r.timespan) END ASC
+    LIMIT page_size OFFSET (page - 1) * page_size;
+END;
+$$ LANGUAGE plpgsql;
+
+-- Same as rsvp.query but for archived reservations. If r_status is null, reservations of any status are returned.
+CREATE FUNCTION rsvp.query_archive(uid text, rid text, during tstzrange, r_status rsvp.reservation_status, page integer default 1, page_size integer default 10, is_desc boolean default false) RETURNS SETOF rsvp.reservations_archive as $$
+BEGIN
+    IF page < 1 THEN
+        page := 1;
+    END IF;
+    IF page_size < 10 or page_size > 100 THEN
+        page_size := 10;
+    END IF;
+
+    RETURN QUERY
+    SELECT *
+    FROM rsvp.reservations_archive r
+    WHERE (uid IS NULL OR r.user_id = uid)
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND (r_status IS NULL OR r.status = r_status)
+      AND during @> r.timespan
+    ORDER BY
+        CASE WHEN is_desc THEN lower(r.timespan) END DESC,
+        CASE WHEN NOT is_desc THEN lower(r.timespan) END ASC
+    LIMIT page_size OFFSET (page - 1) * page_size;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION rsvp.reservation_trigger() RETURNS trigger AS
+$$
+BEGIN
+    -- rows are only hard deleted when they are archived, which is not a change of the reservation.
+    IF TG_OP = 'DELETE' THEN
+        RETURN NULL;
+    END IF;
+    -- only status changes and deletion are recorded for updates
+    IF TG_OP = 'UPDATE' AND OLD.status = NEW.status AND OLD.deleted_at IS NOT DISTINCT FROM NEW.deleted_at THEN
+        RETURN NULL;
+    END IF;
+
+    -- serialize writers of reservations_changes until commit, so change ids become visible in
+    -- commit order and watchers reading changes by id never skip one.
+    PERFORM pg_advisory_xact_lock(hashtext('rsvp.reservations_changes'));
+
+    IF TG_OP = 'INSERT' THEN
+        -- update reservations_changes
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'CREATE', to_jsonb(NEW));
+    ELSIF OLD.deleted_at IS NULL AND NEW.deleted_at IS NOT NULL THEN
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'DELETE', to_jsonb(NEW));
+    ELSE
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'UPDATE', to_jsonb(NEW));
+    END IF;
+    -- notify the reservation change
+    NOTIFY reservation_update;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,5 @@
+-- rejected reservations would conflict with the original constraint.
+DELETE FROM rsvp.reservations WHERE status = 'REJECTED';
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_resource_exclusion;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_resource_exclusion
+    EXCLUDE USING GIST (resource_id WITH =, timespan WITH &&) WHERE (status <> 'CANCELLED' AND deleted_at IS NULL);
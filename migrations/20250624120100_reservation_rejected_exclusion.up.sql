@@ -0,0 +1,4 @@
+-- rejected reservations no longer block the resource.
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_resource_exclusion;
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_resource_exclusion
+    EXCLUDE USING GIST (resource_id WITH =, timespan WITH &&) WHERE (status NOT IN ('CANCELLED', 'REJECTED') AND deleted_at IS NULL);
@@ -0,0 +1,7 @@
+DROP TRIGGER reservations_expire_holds_trigger ON rsvp.reservations;
+DROP FUNCTION rsvp.reservation_expire_holds_trigger;
+DROP FUNCTION rsvp.expire_holds;
+
+DROP INDEX rsvp.reservations_hold_expires_at_idx;
+ALTER TABLE rsvp.reservations_archive DROP COLUMN hold_expires_at;
+ALTER TABLE rsvp.reservations DROP COLUMN hold_expires_at;
@@ -0,0 +1,35 @@
+-- pending reservations hold the resource until confirmed, or until the hold expires if set.
+ALTER TABLE rsvp.reservations ADD COLUMN hold_expires_at timestamptz;
+ALTER TABLE rsvp.reservations_archive ADD COLUMN hold_expires_at timestamptz;
+CREATE INDEX reservations_hold_expires_at_idx ON rsvp.reservations (hold_expires_at) WHERE status = 'PENDING';
+
+-- Cancels pending reservations whose hold has expired, filters are ignored if null. Returns the number of expired holds.
+CREATE FUNCTION rsvp.expire_holds(rid text DEFAULT NULL, during tstzrange DEFAULT NULL, r_id uuid DEFAULT NULL) RETURNS bigint AS $$
+DECLARE
+    expired bigint;
+BEGIN
+    UPDATE rsvp.reservations r
+    SET status = 'CANCELLED', cancelled_at = now(), cancelled_by = 'system', cancel_reason = 'hold expired'
+    WHERE r.status = 'PENDING'
+      AND r.hold_expires_at <= now()
+      AND r.deleted_at IS NULL
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND (during IS NULL OR r.timespan && during)
+      AND (r_id IS NULL OR r.id = r_id);
+    GET DIAGNOSTICS expired = ROW_COUNT;
+    RETURN expired;
+END;
+$$ LANGUAGE plpgsql;
+
+-- expired holds overlapping a new reservation are cancelled before the exclusion is checked, so they
+-- no longer block the resource even if the reaper has not run yet.
+CREATE FUNCTION rsvp.reservation_expire_holds_trigger() RETURNS trigger AS $$
+BEGIN
+    PERFORM rsvp.expire_holds(NEW.resource_id, NEW.timespan);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER reservations_expire_holds_trigger
+    BEFORE INSERT ON rsvp.reservations
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservation_expire_holds_trigger();
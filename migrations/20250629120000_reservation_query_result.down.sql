@@ -0,0 +1,81 @@
+DROP FUNCTION rsvp.query_count;
+DROP FUNCTION rsvp.query;
+DROP FUNCTION rsvp.query_filter;
+
+CREATE FUNCTION rsvp.query(uid text, rid text, during tstzrange, r_statuses rsvp.reservation_status[], page integer default 1, page_size integer default 10, is_desc boolean default false, match_mode text default 'OVERLAPS', after_start timestamptz default null, after_id uuid default null) RETURNS SETOF rsvp.reservations as $$
+DECLARE
+    page_offset integer;
+BEGIN
+    -- page number can not be less than 1
+    IF page < 1 THEN
+        page := 1;
+    END IF;
+    -- pagr size can not be less than 10 or greater than 100
+    IF page_size < 10 or page_size > 100 THEN
+        page_size := 10;
+    END IF;
+    -- a cursor continues right after the last returned row, the page number is ignored then.
+    page_offset := CASE WHEN after_id IS NULL THEN (page - 1) * page_size ELSE 0 END;
+
+    -- rows are ordered by (lower(timespan), id), so the position of a cursor is unique.
+    RETURN QUERY
+    SELECT *
+    FROM rsvp.reservations r
+    WHERE (uid IS NULL OR r.user_id = uid)
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND (r_statuses IS NULL OR r.status = ANY(r_statuses))
+      AND CASE match_mode
+            WHEN 'CONTAINED_BY' THEN during @> r.timespan
+            WHEN 'CONTAINS' THEN r.timespan @> during
+            WHEN 'STARTS_WITHIN' THEN during @> lower(r.timespan)
+            ELSE during && r.timespan
+          END
+      AND r.deleted_at IS NULL
+      AND (after_id IS NULL
+           OR (is_desc AND (lower(r.timespan), r.id) < (after_start, after_id))
+           OR (NOT is_desc AND (lower(r.timespan), r.id) > (after_start, after_id)))
+    ORDER BY
+        CASE WHEN is_desc THEN lower(r.timespan) END DESC,
+        CASE WHEN is_desc THEN r.id END DESC,
+        CASE WHEN NOT is_desc THEN lower(r.timespan) END ASC,
+        CASE WHEN NOT is_desc THEN r.id END ASC
+    LIMIT page_size OFFSET page_offset;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP FUNCTION rsvp.query_archive;
+CREATE FUNCTION rsvp.query_archive(uid text, rid text, during tstzrange, r_statuses rsvp.reservation_status[], page integer default 1, page_size integer default 10, is_desc boolean default false, match_mode text default 'OVERLAPS', after_start timestamptz default null, after_id uuid default null) RETURNS SETOF rsvp.reservations_archive as $$
+DECLARE
+    page_offset integer;
+BEGIN
+    IF page < 1 THEN
+        page := 1;
+    END IF;
+    IF page_size < 10 or page_size > 100 THEN
+        page_size := 10;
+    END IF;
+    page_offset := CASE WHEN after_id IS NULL THEN (page - 1) * page_size ELSE 0 END;
+
+    RETURN QUERY
+    SELECT *
+    FROM rsvp.reservations_archive r
+    WHERE (uid IS NULL OR r.user_id = uid)
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND (r_statuses IS NULL OR r.status = ANY(r_statuses))
+      AND CASE match_mode
+            WHEN 'CONTAINED_BY' THEN during @> r.timespan
+            WHEN 'CONTAINS' THEN r.timespan @> during
+            WHEN 'STARTS_WITHIN' THEN during @> lower(r.timespan)
+            ELSE during && r.timespan
+          END
+      AND (after_id IS NULL
+           OR (is_desc AND (lower(r.timespan), r.id) < (after_start, after_id))
+           OR (NOT is_desc AND (lower(r.timespan), r.id) > (after_start, after_id)))
+    ORDER BY
+        CASE WHEN is_desc THEN lower(r.timespan) END DESC,
+        CASE WHEN is_desc THEN r.id END DESC,
+        CASE WHEN NOT is_desc THEN lower(r.timespan) END ASC,
+        CASE WHEN NOT is_desc THEN r.id END ASC
+    LIMIT page_size OFFSET page_offset;
+END;
+$$ LANGUAGE plpgsql;
@@ -0,0 +1,73 @@
+-- reservations matching the filters of a query, shared by rsvp.query and rsvp.query_count.
+CREATE FUNCTION rsvp.query_filter(uid text, rid text, during tstzrange, r_statuses rsvp.reservation_status[], match_mode text default 'OVERLAPS') RETURNS SETOF rsvp.reservations as $$
+    SELECT *
+    FROM rsvp.reservations r
+    WHERE (uid IS NULL OR r.user_id = uid)
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND (r_statuses IS NULL OR r.status = ANY(r_statuses))
+      AND CASE match_mode
+            WHEN 'CONTAINED_BY' THEN during @> r.timespan
+            WHEN 'CONTAINS' THEN r.timespan @> during
+            WHEN 'STARTS_WITHIN' THEN during @> lower(r.timespan)
+            ELSE during && r.timespan
+          END
+      AND r.deleted_at IS NULL;
+$$ LANGUAGE sql STABLE;
+
+-- Number of reservations matching the filters, regardless of paging.
+CREATE FUNCTION rsvp.query_count(uid text, rid text, during tstzrange, r_statuses rsvp.reservation_status[], match_mode text default 'OVERLAPS') RETURNS bigint as $$
+    SELECT count(*) FROM rsvp.query_filter(uid, rid, during, r_statuses, match_mode);
+$$ LANGUAGE sql STABLE;
+
+-- paging is normalized by the caller, which asks for one more row to know whether there are more.
+CREATE FUNCTION rsvp.query(uid text, rid text, during tstzrange, r_statuses rsvp.reservation_status[], lim integer, off integer default 0, is_desc boolean default false, match_mode text default 'OVERLAPS', after_start timestamptz default null, after_id uuid default null) RETURNS SETOF rsvp.reservations as $$
+BEGIN
+    -- rows are ordered by (lower(timespan), id), so the position of a cursor is unique.
+    RETURN QUERY
+    SELECT *
+    FROM rsvp.query_filter(uid, rid, during, r_statuses, match_mode) r
+    WHERE after_id IS NULL
+       OR (is_desc AND (lower(r.timespan), r.id) < (after_start, after_id))
+       OR (NOT is_desc AND (lower(r.timespan), r.id) > (after_start, after_id))
+    ORDER BY
+        CASE WHEN is_desc THEN lower(r.timespan) END DESC,
+        CASE WHEN is_desc THEN r.id END DESC,
+        CASE WHEN NOT is_desc THEN lower(r.timespan) END ASC,
+        CASE WHEN NOT is_desc THEN r.id END ASC
+    LIMIT lim OFFSET off;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION rsvp.query_archive(uid text, rid text, during tstzrange, r_statuses rsvp.reservation_status[], lim integer, off integer default 0, is_desc boolean default false, match_mode text default 'OVERLAPS', after_start timestamptz default null, after_id uuid default null) RETURNS SETOF rsvp.reservations_archive as $$
+BEGIN
+    RETURN QUERY
+    SELECT *
+    FROM rsvp.reservations_archive r
+    WHERE (uid IS NULL OR r.user_id = uid)
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND (r_statuses IS NULL OR r.status = ANY(r_statuses))
+      AND CASE match_mode
+            WHEN 'CONTAINED_BY' THEN during @> r.timespan
+            WHEN 'CONTAINS' THEN r.timespan @> during
+            WHEN 'STARTS_WITHIN' THEN during @> lower(r.timespan)
+            ELSE during && r.timespan
+          END
+      AND (after_id IS NULL
+           OR (is_desc AND (lower(r.timespan), r.id) < (after_start, after_id))
+           OR (NOT is_desc AND (lower(r.timespan), r.id) > (after_start, after_id)))
+    ORDER BY
+        CASE WHEN is_desc THEN lower(r.timespan) END DESC,
+        CASE WHEN is_desc THEN r.id END DESC,
+        CASE WHEN NOT is_desc THEN lower(r.timespan) END ASC,
+        CASE WHEN NOT is_desc THEN r.id END ASC
+    LIMIT lim OFFSET off;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER reservations_updated_at_trigger ON rsvp.reservations;
+DROP FUNCTION rsvp.reservation_updated_at_trigger;
+
+DROP INDEX rsvp.reservations_updated_at_idx;
+DROP INDEX rsvp.reservations_created_at_idx;
+ALTER TABLE rsvp.reservations_archive DROP COLUMN updated_at, DROP COLUMN created_at;
+ALTER TABLE rsvp.reservations DROP COLUMN updated_at, DROP COLUMN created_at;
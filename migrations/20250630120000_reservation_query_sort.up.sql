@@ -0,0 +1,26 @@
+-- when a reservation was created and last changed, kept for archived reservations too.
+ALTER TABLE rsvp.reservations
+    ADD COLUMN created_at timestamptz NOT NULL DEFAULT now(),
+    ADD COLUMN updated_at timestamptz NOT NULL DEFAULT now();
+ALTER TABLE rsvp.reservations_archive
+    ADD COLUMN created_at timestamptz NOT NULL DEFAULT now(),
+    ADD COLUMN updated_at timestamptz NOT NULL DEFAULT now();
+CREATE INDEX reservations_created_at_idx ON rsvp.reservations (created_at, id);
+CREATE INDEX reservations_updated_at_idx ON rsvp.reservations (updated_at, id);
+
+CREATE FUNCTION rsvp.reservation_updated_at_trigger() RETURNS trigger AS $$
+BEGIN
+    NEW.updated_at := now();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER reservations_updated_at_trigger
+    BEFORE UPDATE ON rsvp.reservations
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservation_updated_at_trigger();
+
+-- queries are built by the reservation crate, which picks the sort key from a fixed list.
+DROP FUNCTION rsvp.query_count;
+DROP FUNCTION rsvp.query;
+DROP FUNCTION rsvp.query_filter;
+DROP FUNCTION rsvp.query_archive;
@@ -0,0 +1,29 @@
+CREATE OR REPLACE FUNCTION rsvp.reservation_trigger() RETURNS trigger AS
+$$
+BEGIN
+    -- rows are only hard deleted when they are archived, which is not a change of the reservation.
+    IF TG_OP = 'DELETE' THEN
+        RETURN NULL;
+    END IF;
+    -- only status changes and deletion are recorded for updates
+    IF TG_OP = 'UPDATE' AND OLD.status = NEW.status AND OLD.deleted_at IS NOT DISTINCT FROM NEW.deleted_at THEN
+        RETURN NULL;
+    END IF;
+
+    -- serialize writers of reservations_changes until commit, so change ids become visible in
+    -- commit order and watchers reading changes by id never skip one.
+    PERFORM pg_advisory_xact_lock(hashtext('rsvp.reservations_changes'));
+
+    IF TG_OP = 'INSERT' THEN
+        -- update reservations_changes
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'CREATE', to_jsonb(NEW));
+    ELSIF OLD.deleted_at IS NULL AND NEW.deleted_at IS NOT NULL THEN
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'DELETE', to_jsonb(NEW));
+    ELSE
+        INSERT INTO rsvp.reservations_changes (reservation_id, op, reservation) VALUES (NEW.id, 'UPDATE', to_jsonb(NEW));
+    END IF;
+    -- notify the reservation change
+    NOTIFY reservation_update;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
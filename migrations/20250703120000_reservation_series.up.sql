@@ -0,0 +1,4 @@
+-- occurrences of a recurring reservation share the series id.
+ALTER TABLE rsvp.reservations ADD COLUMN series_id uuid;
+ALTER TABLE rsvp.reservations_archive ADD COLUMN series_id uuid;
+CREATE INDEX reservations_series_id_idx ON rsvp.reservations (series_id, lower(timespan)) WHERE series_id IS NOT NULL;
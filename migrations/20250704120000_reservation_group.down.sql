@@ -0,0 +1,3 @@
+DROP INDEX rsvp.reservations_group_id_idx;
+ALTER TABLE rsvp.reservations_archive DROP COLUMN group_id;
+ALTER TABLE rsvp.reservations DROP COLUMN group_id;
@@ -0,0 +1,4 @@
+-- reservations made together share the group id, so they could be confirmed or cancelled together.
+ALTER TABLE rsvp.reservations ADD COLUMN group_id uuid;
+ALTER TABLE rsvp.reservations_archive ADD COLUMN group_id uuid;
+CREATE INDEX reservations_group_id_idx ON rsvp.reservations (group_id) WHERE group_id IS NOT NULL;
@@ -0,0 +1,34 @@
+-- restore availability without buffers.
+CREATE OR REPLACE FUNCTION rsvp.availability(rid text, during tstzrange, min_duration interval DEFAULT '0', granularity interval DEFAULT NULL) RETURNS SETOF tstzrange AS $$
+    WITH busy AS (
+        SELECT COALESCE(range_agg(r.timespan), '{}') AS timespans
+        FROM rsvp.reservations r
+        WHERE r.resource_id = rid
+          AND r.timespan && during
+          AND r.status NOT IN ('CANCELLED', 'REJECTED')
+          AND r.deleted_at IS NULL
+          AND (r.status <> 'PENDING' OR r.hold_expires_at IS NULL OR r.hold_expires_at > now())
+    ), free AS (
+        SELECT unnest(tstzmultirange(during) - busy.timespans) AS slot FROM busy
+    ), aligned AS (
+        SELECT
+            CASE WHEN granularity IS NULL THEN lower(slot)
+                 ELSE date_bin(granularity, lower(slot) + granularity - interval '1 microsecond', 'epoch')
+            END AS s,
+            CASE WHEN granularity IS NULL THEN upper(slot)
+                 ELSE date_bin(granularity, upper(slot), 'epoch')
+            END AS e
+        FROM free
+    )
+    SELECT tstzrange(s, e) FROM aligned WHERE e > s AND e - s >= min_duration ORDER BY s;
+$$ LANGUAGE sql STABLE;
+
+ALTER TABLE rsvp.reservations DROP CONSTRAINT reservations_buffer_exclusion;
+DROP TRIGGER resource_buffers_trigger ON rsvp.resource_buffers;
+DROP FUNCTION rsvp.resource_buffers_trigger;
+DROP TRIGGER reservations_effective_timespan_trigger ON rsvp.reservations;
+DROP FUNCTION rsvp.reservation_effective_timespan_trigger;
+ALTER TABLE rsvp.reservations_archive DROP COLUMN effective_timespan;
+ALTER TABLE rsvp.reservations DROP COLUMN effective_timespan;
+DROP FUNCTION rsvp.buffered_timespan;
+DROP TABLE rsvp.resource_buffers;
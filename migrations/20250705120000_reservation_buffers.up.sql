@@ -0,0 +1,89 @@
+-- time kept free before and after every reservation of a resource, e.g. to clean a room.
+CREATE TABLE rsvp.resource_buffers (
+    resource_id VARCHAR(64) NOT NULL,
+    buffer_before interval NOT NULL DEFAULT '0',
+    buffer_after interval NOT NULL DEFAULT '0',
+
+    CONSTRAINT resource_buffers_pkey PRIMARY KEY (resource_id),
+    CONSTRAINT resource_buffers_non_negative CHECK (buffer_before >= '0' AND buffer_after >= '0')
+);
+
+-- the timespan widened by the buffers of the resource.
+CREATE FUNCTION rsvp.buffered_timespan(rid text, span tstzrange) RETURNS tstzrange AS $$
+    SELECT COALESCE(
+        (SELECT tstzrange(lower(span) - b.buffer_before, upper(span) + b.buffer_after)
+         FROM rsvp.resource_buffers b WHERE b.resource_id = rid),
+        span
+    );
+$$ LANGUAGE sql STABLE;
+
+-- the range a reservation keeps the resource busy, start and end of the reservation stay as they are.
+ALTER TABLE rsvp.reservations ADD COLUMN effective_timespan tstzrange;
+ALTER TABLE rsvp.reservations_archive ADD COLUMN effective_timespan tstzrange;
+UPDATE rsvp.reservations SET effective_timespan = timespan;
+ALTER TABLE rsvp.reservations ALTER COLUMN effective_timespan SET NOT NULL;
+
+CREATE FUNCTION rsvp.reservation_effective_timespan_trigger() RETURNS trigger AS $$
+BEGIN
+    NEW.effective_timespan := rsvp.buffered_timespan(NEW.resource_id, NEW.timespan);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER reservations_effective_timespan_trigger
+    BEFORE INSERT OR UPDATE OF timespan, resource_id ON rsvp.reservations
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservation_effective_timespan_trigger();
+
+-- changed buffers apply to reservations not over yet, the change fails if they would conflict then.
+CREATE FUNCTION rsvp.resource_buffers_trigger() RETURNS trigger AS $$
+BEGIN
+    UPDATE rsvp.reservations r
+    SET effective_timespan = rsvp.buffered_timespan(r.resource_id, r.timespan)
+    WHERE r.resource_id IN (OLD.resource_id, NEW.resource_id) AND upper(r.timespan) > now();
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER resource_buffers_trigger
+    AFTER INSERT OR UPDATE OR DELETE ON rsvp.resource_buffers
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.resource_buffers_trigger();
+
+-- reservations_resource_exclusion still rejects overlapping timespans. Being deferrable, this one is
+-- only checked after it, so its violations are the conflicts caused by buffers alone.
+ALTER TABLE rsvp.reservations ADD CONSTRAINT reservations_buffer_exclusion
+    EXCLUDE USING GIST (resource_id WITH =, effective_timespan WITH &&)
+    WHERE (status NOT IN ('CANCELLED', 'REJECTED') AND deleted_at IS NULL)
+    DEFERRABLE INITIALLY IMMEDIATE;
+
+-- a free slot has to leave the buffers of its own reservation free too, so each busy range is widened
+-- by the buffers the other way round.
+CREATE OR REPLACE FUNCTION rsvp.availability(rid text, during tstzrange, min_duration interval DEFAULT '0', granularity interval DEFAULT NULL) RETURNS SETOF tstzrange AS $$
+    WITH buffers AS (
+        SELECT COALESCE(max(b.buffer_before), '0') AS buffer_before,
+               COALESCE(max(b.buffer_after), '0') AS buffer_after
+        FROM rsvp.resource_buffers b
+        WHERE b.resource_id = rid
+    ), busy AS (
+        SELECT COALESCE(range_agg(s.span), '{}') AS timespans
+        FROM rsvp.reservations r, buffers b,
+            LATERAL (SELECT tstzrange(lower(r.effective_timespan) - b.buffer_after,
+                                      upper(r.effective_timespan) + b.buffer_before) AS span) s
+        WHERE r.resource_id = rid
+          AND s.span && during
+          AND r.status NOT IN ('CANCELLED', 'REJECTED')
+          AND r.deleted_at IS NULL
+          AND (r.status <> 'PENDING' OR r.hold_expires_at IS NULL OR r.hold_expires_at > now())
+    ), free AS (
+        SELECT unnest(tstzmultirange(during) - busy.timespans) AS slot FROM busy
+    ), aligned AS (
+        SELECT
+            CASE WHEN granularity IS NULL THEN lower(slot)
+                 ELSE date_bin(granularity, lower(slot) + granularity - interval '1 microsecond', 'epoch')
+            END AS s,
+            CASE WHEN granularity IS NULL THEN upper(slot)
+                 ELSE date_bin(granularity, upper(slot), 'epoch')
+            END AS e
+        FROM free
+    )
+    SELECT tstzrange(s, e) FROM aligned WHERE e > s AND e - s >= min_duration ORDER BY s;
+$$ LANGUAGE sql STABLE;
@@ -0,0 +1,31 @@
+DROP TRIGGER reservations_expire_holds_trigger ON rsvp.reservations;
+CREATE TRIGGER reservations_expire_holds_trigger
+    BEFORE INSERT ON rsvp.reservations
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservation_expire_holds_trigger();
+
+CREATE OR REPLACE FUNCTION rsvp.reservation_expire_holds_trigger() RETURNS trigger AS $$
+BEGIN
+    PERFORM rsvp.expire_holds(NEW.resource_id, NEW.timespan);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP FUNCTION rsvp.expire_holds;
+CREATE FUNCTION rsvp.expire_holds(rid text DEFAULT NULL, during tstzrange DEFAULT NULL, r_id uuid DEFAULT NULL) RETURNS bigint AS $$
+DECLARE
+    expired bigint;
+BEGIN
+    UPDATE rsvp.reservations r
+    SET status = 'CANCELLED', cancelled_at = now(), cancelled_by = 'system', cancel_reason = 'hold expired'
+    WHERE r.status = 'PENDING'
+      AND r.hold_expires_at <= now()
+      AND r.deleted_at IS NULL
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND (during IS NULL OR r.timespan && during)
+      AND (r_id IS NULL OR r.id = r_id);
+    GET DIAGNOSTICS expired = ROW_COUNT;
+    RETURN expired;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP FUNCTION rsvp.system_user_id;
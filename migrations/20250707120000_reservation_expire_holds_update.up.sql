@@ -0,0 +1,39 @@
+-- owner of what the system does rather than a user, the same as abi::SYSTEM_USER_ID.
+CREATE FUNCTION rsvp.system_user_id() RETURNS text AS $$
+    SELECT 'system';
+$$ LANGUAGE sql IMMUTABLE;
+
+-- Cancels pending reservations whose hold has expired, filters are ignored if null. The reservation
+-- skip_id is left as it is. Returns the number of expired holds.
+DROP FUNCTION rsvp.expire_holds;
+CREATE FUNCTION rsvp.expire_holds(rid text DEFAULT NULL, during tstzrange DEFAULT NULL, r_id uuid DEFAULT NULL, skip_id uuid DEFAULT NULL) RETURNS bigint AS $$
+DECLARE
+    expired bigint;
+BEGIN
+    UPDATE rsvp.reservations r
+    SET status = 'CANCELLED', cancelled_at = now(), cancelled_by = rsvp.system_user_id(), cancel_reason = 'hold expired'
+    WHERE r.status = 'PENDING'
+      AND r.hold_expires_at <= now()
+      AND r.deleted_at IS NULL
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND (during IS NULL OR r.timespan && during)
+      AND (r_id IS NULL OR r.id = r_id)
+      AND (skip_id IS NULL OR r.id <> skip_id);
+    GET DIAGNOSTICS expired = ROW_COUNT;
+    RETURN expired;
+END;
+$$ LANGUAGE plpgsql;
+
+-- expired holds in the way of a reservation being made or moved are cancelled before the exclusion is
+-- checked, so they no longer block the resource even if the reaper has not run yet.
+CREATE OR REPLACE FUNCTION rsvp.reservation_expire_holds_trigger() RETURNS trigger AS $$
+BEGIN
+    PERFORM rsvp.expire_holds(NEW.resource_id, NEW.timespan, skip_id => NEW.id);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP TRIGGER reservations_expire_holds_trigger ON rsvp.reservations;
+CREATE TRIGGER reservations_expire_holds_trigger
+    BEFORE INSERT OR UPDATE OF timespan, resource_id ON rsvp.reservations
+    FOR EACH ROW EXECUTE PROCEDURE rsvp.reservation_expire_holds_trigger();
@@ -0,0 +1,26 @@
+-- Same as before, but holds are matched by the range they keep the resource busy, buffers included.
+-- Callers pass the buffered timespan of the reservation the holds are in the way of.
+CREATE OR REPLACE FUNCTION rsvp.expire_holds(rid text DEFAULT NULL, during tstzrange DEFAULT NULL, r_id uuid DEFAULT NULL, skip_id uuid DEFAULT NULL) RETURNS bigint AS $$
+DECLARE
+    expired bigint;
+BEGIN
+    UPDATE rsvp.reservations r
+    SET status = 'CANCELLED', cancelled_at = now(), cancelled_by = rsvp.system_user_id(), cancel_reason = 'hold expired'
+    WHERE r.status = 'PENDING'
+      AND r.hold_expires_at <= now()
+      AND r.deleted_at IS NULL
+      AND (rid IS NULL OR r.resource_id = rid)
+      AND (during IS NULL OR r.effective_timespan && during)
+      AND (r_id IS NULL OR r.id = r_id)
+      AND (skip_id IS NULL OR r.id <> skip_id);
+    GET DIAGNOSTICS expired = ROW_COUNT;
+    RETURN expired;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION rsvp.reservation_expire_holds_trigger() RETURNS trigger AS $$
+BEGIN
+    PERFORM rsvp.expire_holds(NEW.resource_id, rsvp.buffered_timespan(NEW.resource_id, NEW.timespan), skip_id => NEW.id);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
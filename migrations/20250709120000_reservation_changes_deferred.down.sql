@@ -0,0 +1,4 @@
+DROP TRIGGER reservation_trigger ON rsvp.reservations;
+CREATE TRIGGER reservation_trigger
+    AFTER INSERT OR UPDATE OR DELETE ON rsvp.reservations
+    FOR EACH ROW EXECUTE FUNCTION rsvp.reservation_trigger();
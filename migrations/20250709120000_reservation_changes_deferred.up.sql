@@ -0,0 +1,8 @@
+-- record changes when the transaction commits rather than when the row changes, so the advisory lock
+-- of rsvp.reservation_trigger() is only held while committing instead of for the whole transaction.
+-- changes of rolled back transactions or savepoints are never recorded.
+DROP TRIGGER reservation_trigger ON rsvp.reservations;
+CREATE CONSTRAINT TRIGGER reservation_trigger
+    AFTER INSERT OR UPDATE OR DELETE ON rsvp.reservations
+    DEFERRABLE INITIALLY DEFERRED
+    FOR EACH ROW EXECUTE FUNCTION rsvp.reservation_trigger();
@@ -0,0 +1,24 @@
+CREATE OR REPLACE FUNCTION rsvp.reservation_updated_at_trigger() RETURNS trigger AS $$
+BEGIN
+    NEW.updated_at := now();
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION rsvp.resource_buffers_trigger() RETURNS trigger AS $$
+BEGIN
+    UPDATE rsvp.reservations r
+    SET effective_timespan = rsvp.buffered_timespan(r.resource_id, r.timespan)
+    WHERE r.resource_id IN (OLD.resource_id, NEW.resource_id) AND upper(r.timespan) > now();
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION rsvp.reservation_effective_timespan_trigger() RETURNS trigger AS $$
+BEGIN
+    NEW.effective_timespan := rsvp.buffered_timespan(NEW.resource_id, NEW.timespan);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+DROP FUNCTION rsvp.lock_resource_buffers;
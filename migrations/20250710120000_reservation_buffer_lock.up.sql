@@ -0,0 +1,47 @@
+-- buffers of a resource could not change while reservations of it are being written with the old ones.
+-- writers of reservations share the lock, so they do not wait for each other.
+CREATE FUNCTION rsvp.lock_resource_buffers(rid text, exclusive boolean) RETURNS void AS $$
+BEGIN
+    IF exclusive THEN
+        PERFORM pg_advisory_xact_lock(hashtext('rsvp.resource_buffers'), hashtext(rid));
+    ELSE
+        PERFORM pg_advisory_xact_lock_shared(hashtext('rsvp.resource_buffers'), hashtext(rid));
+    END IF;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE OR REPLACE FUNCTION rsvp.reservation_effective_timespan_trigger() RETURNS trigger AS $$
+BEGIN
+    PERFORM rsvp.lock_resource_buffers(NEW.resource_id, false);
+    NEW.effective_timespan := rsvp.buffered_timespan(NEW.resource_id, NEW.timespan);
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
+
+-- only reservations whose effective timespan actually changes are rewritten.
+CREATE OR REPLACE FUNCTION rsvp.resource_buffers_trigger() RETURNS trigger AS $$
+BEGIN
+    IF TG_OP <> 'INSERT' THEN
+        PERFORM rsvp.lock_resource_buffers(OLD.resource_id, true);
+    END IF;
+    IF TG_OP <> 'DELETE' THEN
+        PERFORM rsvp.lock_resource_buffers(NEW.resource_id, true);
+    END IF;
+    UPDATE rsvp.reservations r
+    SET effective_timespan = rsvp.buffered_timespan(r.resource_id, r.timespan)
+    WHERE r.resource_id IN (OLD.resource_id, NEW.resource_id) AND upper(r.timespan) > now()
+        AND r.effective_timespan <> rsvp.buffered_timespan(r.resource_id, r.timespan);
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+-- the effective timespan is derived from the buffers of the resource, changing it alone does not
+-- change the reservation.
+CREATE OR REPLACE FUNCTION rsvp.reservation_updated_at_trigger() RETURNS trigger AS $$
+BEGIN
+    IF to_jsonb(NEW) - 'effective_timespan' - 'updated_at' <> to_jsonb(OLD) - 'effective_timespan' - 'updated_at' THEN
+        NEW.updated_at := now();
+    END IF;
+    RETURN NEW;
+END;
+$$ LANGUAGE plpgsql;
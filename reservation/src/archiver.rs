@@ -0,0 +1,21 @@
+use std::time::Duration as StdDuration;
+
+use chrono::Duration;
+use tokio::task::JoinHandle;
+
+use crate::{ReservationManager, Rsvp};
+
+impl ReservationManager {
+    /// Archives reservations older than `max_age` every `period` in a background task.
+    pub fn spawn_archiver(&self, max_age: Duration, period: StdDuration) -> JoinHandle<()> {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                // a failed run is simply retried on the next tick.
+                let _ = manager.archive(max_age).await;
+            }
+        })
+    }
+}
@@ -1,33 +1,141 @@
+mod archiver;
 mod manager;
+mod query;
+mod reaper;
+mod watcher;
 use async_trait::async_trait;
 
+use chrono::Duration;
+use futures::stream::BoxStream;
 use sqlx::PgPool;
+use tokio::sync::broadcast;
 
 pub type ReservationId = String;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ReservationManager {
     pool: PgPool,
 }
 
+/// Broadcasts reservation changes recorded in `rsvp.reservations_changes` to subscribers.
+#[derive(Debug, Clone)]
+pub struct ReservationWatcher {
+    sender: broadcast::Sender<abi::WatchResponse>,
+    pool: PgPool,
+}
+
 #[async_trait]
 pub trait Rsvp {
     /// Make a reservation.
     async fn reserve(&self, rsvp: abi::Reservation) -> Result<abi::Reservation, abi::Error>;
-    /// Change reservation status.
+    /// Make several reservations sharing a group id in one transaction. Nothing is reserved if any
+    /// reservation conflicts, every conflict is reported.
+    async fn reserve_many(
+        &self,
+        rsvps: Vec<abi::Reservation>,
+    ) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// Import reservations in bulk, reporting whether each one is created, conflicts or is invalid.
+    async fn import(
+        &self,
+        rsvps: Vec<abi::Reservation>,
+        options: abi::ImportOptions,
+    ) -> Result<abi::ImportReport, abi::Error>;
+    /// Make a recurring reservation, the reservation is the first occurrence. Nothing is reserved if
+    /// any occurrence conflicts, every conflict is reported.
+    async fn reserve_series(
+        &self,
+        rsvp: abi::Reservation,
+        recurrence: abi::Recurrence,
+    ) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// Confirm a pending reservation.
     async fn change_status(&self, id: ReservationId) -> Result<abi::Reservation, abi::Error>;
+    /// Confirm every reservation of the group, or none if any of them is cancelled or rejected.
+    async fn confirm_group(&self, group_id: String) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// Move reservation to the given status, rejecting transitions the state machine forbids.
+    /// Reservations could not be blocked this way, see `block`.
+    async fn transition(
+        &self,
+        id: ReservationId,
+        status: abi::ReservationStatus,
+    ) -> Result<abi::Reservation, abi::Error>;
     /// Update reservation note.
     async fn update_note(
         &self,
         id: ReservationId,
         note: String,
     ) -> Result<abi::Reservation, abi::Error>;
-    /// Delete reservation.
+    /// Move reservation to another time range and/or resource.
+    async fn reschedule(
+        &self,
+        request: abi::RescheduleRequest,
+    ) -> Result<abi::Reservation, abi::Error>;
+    /// Cancel reservation, recording who cancelled it and why.
+    async fn cancel(
+        &self,
+        id: ReservationId,
+        cancelled_by: String,
+        reason: String,
+    ) -> Result<abi::Reservation, abi::Error>;
+    /// Cancel every reservation of the group which is not cancelled yet.
+    async fn cancel_group(
+        &self,
+        group_id: String,
+        cancelled_by: String,
+        reason: String,
+    ) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// Move every reservation matching the query to CONFIRMED, REJECTED or CANCELLED in one transaction,
+    /// skipping those which could not move to it. With `dry_run` the changes are returned but not kept.
+    async fn bulk_transition(
+        &self,
+        query: abi::ReservationQuery,
+        status: abi::ReservationStatus,
+        reason: String,
+        dry_run: bool,
+    ) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// Update the note and/or time of occurrences in scope of a recurring reservation.
+    async fn update_series(
+        &self,
+        request: abi::UpdateSeriesRequest,
+    ) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// Cancel occurrences in scope of a recurring reservation which are not cancelled yet.
+    async fn cancel_series(
+        &self,
+        id: ReservationId,
+        scope: abi::SeriesScope,
+        cancelled_by: String,
+        reason: String,
+    ) -> Result<Vec<abi::Reservation>, abi::Error>;
+    /// Block a resource for a time range, cancelling the overlapping reservations if asked to.
+    async fn block(&self, request: abi::BlockRequest) -> Result<abi::BlockResponse, abi::Error>;
+    /// Set the time kept free before and after every reservation of a resource.
+    async fn set_buffers(&self, request: abi::SetBuffersRequest) -> Result<(), abi::Error>;
+    /// Find the free slots of a resource within a time window.
+    async fn availability(
+        &self,
+        request: abi::AvailabilityRequest,
+    ) -> Result<Vec<abi::TimeSlot>, abi::Error>;
+    /// Find the earliest slot any of the candidate resources is free, reserving it if asked to.
+    async fn find_slot(
+        &self,
+        request: abi::FindSlotRequest,
+    ) -> Result<abi::FindSlotResponse, abi::Error>;
+    /// Cancel pending reservations whose hold has expired, returns the number of expired holds.
+    async fn expire_holds(&self) -> Result<u64, abi::Error>;
+    /// Delete reservation. Deleted reservations are kept until archived.
     async fn delete(&self, id: ReservationId) -> Result<(), abi::Error>;
     /// Get reservation by id.
     async fn get(&self, id: ReservationId) -> Result<abi::Reservation, abi::Error>;
-    /// Query reservations.
-    async fn query(
+    /// Query one page of reservations.
+    async fn query(&self, query: abi::ReservationQuery) -> Result<abi::QueryResult, abi::Error>;
+    /// Stream every reservation matching the query as it is read, `page` and `page_size` are ignored.
+    async fn query_stream(
+        &self,
+        query: abi::ReservationQuery,
+    ) -> Result<BoxStream<'static, Result<abi::Reservation, abi::Error>>, abi::Error>;
+    /// Move reservations ended, cancelled or deleted more than `max_age` ago into the archive.
+    async fn archive(&self, max_age: Duration) -> Result<u64, abi::Error>;
+    /// Query archived reservations. If status is UNKNOWN, archived reservations of any status are returned.
+    async fn query_archive(
         &self,
         query: abi::ReservationQuery,
     ) -> Result<Vec<abi::Reservation>, abi::Error>;
@@ -1,43 +1,146 @@
-use crate::{ReservationId, ReservationManager, Rsvp};
-use abi::Validator;
+use std::collections::HashMap;
+
+use crate::{
+    ReservationId, ReservationManager, Rsvp,
+    query::{QueryTable, select_all, select_count, select_page},
+};
+use abi::{Validator, timestamp_to_utc_time};
 use async_trait::async_trait;
-use sqlx::{PgPool, Row, types::Uuid};
+use chrono::{DateTime, Duration, Utc};
+use futures::{StreamExt, stream::BoxStream};
+use sqlx::{
+    Acquire, PgConnection, PgPool, Postgres, QueryBuilder, Row, Transaction,
+    postgres::types::PgRange, types::Uuid,
+};
+use tokio::sync::mpsc;
+
+/// Max number of reservations read ahead of a slow `query_stream` consumer.
+const STREAM_BUFFER: usize = 128;
+/// Max number of rows inserted by one statement of `import`.
+const IMPORT_BATCH_SIZE: usize = 500;
+/// Max number of times `find_slot` searches again after the found slot is taken concurrently.
+const FIND_SLOT_RETRIES: usize = 8;
 
 #[async_trait]
 impl Rsvp for ReservationManager {
-    async fn reserve(&self, mut rsvp: abi::Reservation) -> Result<abi::Reservation, abi::Error> {
-        // Validate the reservation.
-        rsvp.validate()?;
+    async fn reserve(&self, rsvp: abi::Reservation) -> Result<abi::Reservation, abi::Error> {
+        let mut conn = self.pool.acquire().await?;
+        insert_reservation(&mut conn, rsvp, None, None).await
+    }
+
+    async fn reserve_many(
+        &self,
+        rsvps: Vec<abi::Reservation>,
+    ) -> Result<Vec<abi::Reservation>, abi::Error> {
+        if rsvps.is_empty() {
+            return Ok(rsvps);
+        }
+        let mut tx = self.pool.begin().await?;
+        let group_id: Uuid = sqlx::query("SELECT gen_random_uuid()")
+            .fetch_one(&mut tx)
+            .await?
+            .get(0);
+        let rsvps = insert_all(&mut tx, rsvps, None, Some(group_id)).await?;
+        tx.commit().await?;
+
+        Ok(rsvps)
+    }
 
-        // Convert the start and end times to UTC.
-        let timespan = rsvp.get_timespan();
+    async fn import(
+        &self,
+        rsvps: Vec<abi::Reservation>,
+        options: abi::ImportOptions,
+    ) -> Result<abi::ImportReport, abi::Error> {
+        let mut rows: Vec<_> = std::iter::repeat_with(|| None).take(rsvps.len()).collect();
+        // invalid rows are reported without touching the database.
+        let mut valid = Vec::with_capacity(rsvps.len());
+        for (i, rsvp) in rsvps.into_iter().enumerate() {
+            match check_reservation(&rsvp) {
+                Ok(()) => valid.push((i, rsvp)),
+                Err(e) => rows[i] = Some(abi::ImportOutcome::Invalid(e)),
+            }
+        }
 
-        let status = abi::ReservationStatus::try_from(rsvp.status)
-            .unwrap_or(abi::ReservationStatus::Pending);
+        let mut tx = self.pool.begin().await?;
+        for batch in valid.chunks(IMPORT_BATCH_SIZE) {
+            let mut savepoint = tx.begin().await?;
+            match insert_batch(&mut savepoint, batch).await {
+                Ok(created) => {
+                    savepoint.commit().await?;
+                    for ((i, _), rsvp) in batch.iter().zip(created) {
+                        rows[*i] = Some(abi::ImportOutcome::Created(Box::new(rsvp)));
+                    }
+                    continue;
+                }
+                Err(abi::Error::ConflictReservation(_)) => savepoint.rollback().await?,
+                Err(e) => return Err(e),
+            }
 
-        // execute the SQL query to insert the reservation and return the reservation ID.
-        let id: Uuid = sqlx::query("INSERT INTO rsvp.reservations (user_id, resource_id, timespan, note, status) VALUES ($1, $2, $3, $4, $5::rsvp.reservation_status) RETURNING id")
-            .bind(rsvp.user_id.clone())
-            .bind(rsvp.resource_id.clone())
-            .bind(timespan)
-            .bind(rsvp.note.clone())
-            .bind(status.to_string())
-            .fetch_one(&self.pool)
-            .await?.get(0);
+            // the batch conflicts somewhere, its rows are inserted one by one to find the conflicts.
+            for (i, rsvp) in batch {
+                let mut savepoint = tx.begin().await?;
+                match insert_reservation(&mut savepoint, rsvp.clone(), None, None).await {
+                    Ok(rsvp) => {
+                        savepoint.commit().await?;
+                        rows[*i] = Some(abi::ImportOutcome::Created(Box::new(rsvp)));
+                    }
+                    Err(abi::Error::ConflictReservation(info)) => {
+                        savepoint.rollback().await?;
+                        rows[*i] = Some(abi::ImportOutcome::Conflict(info));
+                    }
+                    Err(e) => return Err(e),
+                }
+            }
+        }
 
-        rsvp.id = id.to_string();
+        let mut report = abi::ImportReport {
+            // every row is either invalid or inserted above.
+            rows: rows.into_iter().map(Option::unwrap).collect(),
+            committed: false,
+        };
+        let failed = report.failed() > 0 && options.policy == abi::ImportPolicy::AllOrNothing;
+        if options.dry_run || failed {
+            tx.rollback().await?;
+        } else {
+            tx.commit().await?;
+            report.committed = true;
+        }
 
-        Ok(rsvp)
+        Ok(report)
+    }
+
+    async fn reserve_series(
+        &self,
+        rsvp: abi::Reservation,
+        recurrence: abi::Recurrence,
+    ) -> Result<Vec<abi::Reservation>, abi::Error> {
+        let occurrences = recurrence.expand(&rsvp)?;
+        let mut tx = self.pool.begin().await?;
+        let series_id: Uuid = sqlx::query("SELECT gen_random_uuid()")
+            .fetch_one(&mut tx)
+            .await?
+            .get(0);
+        let rsvps = insert_all(&mut tx, occurrences, Some(series_id), None).await?;
+        tx.commit().await?;
+
+        Ok(rsvps)
     }
 
     async fn change_status(&self, id: ReservationId) -> Result<abi::Reservation, abi::Error> {
-        let id = Uuid::parse_str(&id).map_err(|_| abi::Error::InvalidReservationId(id.clone()))?;
-        // if current status is `pending`, change it to `confirmed`, otherwie do nothing.
-        let rsvp: abi::Reservation = sqlx::query_as(
-            "UPDATE rsvp.reservations SET status = 'CONFIRMED' WHERE id = $1::uuid AND status = 'PENDING' RETURNING *"
-        ).bind(id).fetch_one(&self.pool).await?;
+        // only a pending reservation could be confirmed.
+        self.transition(id, abi::ReservationStatus::Confirmed).await
+    }
 
-        Ok(rsvp)
+    async fn transition(
+        &self,
+        id: ReservationId,
+        status: abi::ReservationStatus,
+    ) -> Result<abi::Reservation, abi::Error> {
+        // only an admin could block, which takes the resource from the user, see `block`.
+        if status == abi::ReservationStatus::Blocked {
+            return Err(abi::Error::InvalidStatus(status));
+        }
+        self.update_status(id, status, "", "").await
     }
 
     async fn update_note(
@@ -47,7 +150,7 @@ impl Rsvp for ReservationManager {
     ) -> Result<abi::Reservation, abi::Error> {
         let id = Uuid::parse_str(&id).map_err(|_| abi::Error::InvalidReservationId(id.clone()))?;
         let rsvp: abi::Reservation = sqlx::query_as(
-            "UPDATE rsvp.reservations SET note = $1 WHERE id = $2::uuid RETURNING *",
+            "UPDATE rsvp.reservations SET note = $1 WHERE id = $2::uuid AND deleted_at IS NULL RETURNING *",
         )
         .bind(note)
         .bind(id)
@@ -57,14 +160,440 @@ impl Rsvp for ReservationManager {
         Ok(rsvp)
     }
 
-    async fn delete(&self, id: ReservationId) -> Result<(), abi::Error> {
-        let id = Uuid::parse_str(&id).map_err(|_| abi::Error::InvalidReservationId(id.clone()))?;
-        // Execute the SQL query to delete the reservation by ID.
-        let _rows_affected = sqlx::query("DELETE FROM rsvp.reservations WHERE id = $1::uuid")
+    async fn reschedule(
+        &self,
+        request: abi::RescheduleRequest,
+    ) -> Result<abi::Reservation, abi::Error> {
+        request.validate()?;
+        let id = Uuid::parse_str(&request.id)
+            .map_err(|_| abi::Error::InvalidReservationId(request.id.clone()))?;
+        // an expired hold is cancelled for good rather than moved.
+        sqlx::query("SELECT rsvp.expire_holds(r_id => $1)")
             .bind(id)
             .execute(&self.pool)
+            .await?;
+
+        let mut tx = self.pool.begin().await?;
+        let row = sqlx::query(
+            "SELECT status, resource_id FROM rsvp.reservations WHERE id = $1::uuid AND deleted_at IS NULL FOR UPDATE",
+        )
+        .bind(id)
+        .fetch_one(&mut tx)
+        .await?;
+        let status = abi::ReservationStatus::from(row.get::<abi::RsvpStatus, _>(0));
+        if status.is_final() {
+            return Err(abi::Error::FinalStatus(status));
+        }
+        let resource_id = if request.resource_id.is_empty() {
+            row.get(1)
+        } else {
+            request.resource_id.clone()
+        };
+
+        // expired holds in the way are released by a trigger, like they are for a new reservation, and
+        // the exclusion constraint reports conflicts the same way.
+        let rsvp = sqlx::query_as(
+            "UPDATE rsvp.reservations SET resource_id = $2, timespan = $3 WHERE id = $1::uuid RETURNING *",
+        )
+        .bind(id)
+        .bind(resource_id)
+        .bind(request.get_timespan())
+        .fetch_one(&mut tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(rsvp)
+    }
+
+    async fn confirm_group(&self, group_id: String) -> Result<Vec<abi::Reservation>, abi::Error> {
+        let group_id = parse_group_id(&group_id)?;
+        // expired holds are cancelled for good, which fails the confirmation below.
+        sqlx::query(
+            "SELECT rsvp.expire_holds(r_id => id) FROM rsvp.reservations WHERE group_id = $1",
+        )
+        .bind(group_id)
+        .execute(&self.pool)
+        .await?;
+
+        let mut tx = self.pool.begin().await?;
+        let mut rsvps = Vec::new();
+        for rsvp in lock_group(&mut tx, group_id).await? {
+            let status = abi::ReservationStatus::try_from(rsvp.status).unwrap_or_default();
+            let rsvp = match status {
+                abi::ReservationStatus::Confirmed => rsvp,
+                status if status.is_final() => return Err(abi::Error::FinalStatus(status)),
+                _ => {
+                    let id = Uuid::parse_str(&rsvp.id)
+                        .map_err(|_| abi::Error::InvalidReservationId(rsvp.id.clone()))?;
+                    let status = abi::ReservationStatus::Confirmed;
+                    set_status(&mut tx, id, status, "", "").await?
+                }
+            };
+            rsvps.push(rsvp);
+        }
+        tx.commit().await?;
+
+        Ok(rsvps)
+    }
+
+    async fn cancel(
+        &self,
+        id: ReservationId,
+        cancelled_by: String,
+        reason: String,
+    ) -> Result<abi::Reservation, abi::Error> {
+        let status = abi::ReservationStatus::Cancelled;
+        match self
+            .update_status(id.clone(), status, &cancelled_by, &reason)
+            .await
+        {
+            // a cancelled reservation keeps who cancelled it first.
+            Err(abi::Error::InvalidTransition {
+                from: abi::ReservationStatus::Cancelled,
+                ..
+            }) => self.get(id).await,
+            res => res,
+        }
+    }
+
+    async fn cancel_group(
+        &self,
+        group_id: String,
+        cancelled_by: String,
+        reason: String,
+    ) -> Result<Vec<abi::Reservation>, abi::Error> {
+        let group_id = parse_group_id(&group_id)?;
+        let mut tx = self.pool.begin().await?;
+
+        let mut rsvps = Vec::new();
+        for rsvp in lock_group(&mut tx, group_id).await? {
+            let status = abi::ReservationStatus::try_from(rsvp.status).unwrap_or_default();
+            if status.is_final() {
+                continue;
+            }
+            let id = Uuid::parse_str(&rsvp.id)
+                .map_err(|_| abi::Error::InvalidReservationId(rsvp.id.clone()))?;
+            let status = abi::ReservationStatus::Cancelled;
+            rsvps.push(set_status(&mut tx, id, status, &cancelled_by, &reason).await?);
+        }
+        tx.commit().await?;
+
+        Ok(rsvps)
+    }
+
+    async fn bulk_transition(
+        &self,
+        query: abi::ReservationQuery,
+        status: abi::ReservationStatus,
+        reason: String,
+        dry_run: bool,
+    ) -> Result<Vec<abi::Reservation>, abi::Error> {
+        query.validate()?;
+        // blocks are owned by the system and made by `block`, not by turning reservations into them.
+        if !matches!(
+            status,
+            abi::ReservationStatus::Confirmed
+                | abi::ReservationStatus::Rejected
+                | abi::ReservationStatus::Cancelled
+        ) {
+            return Err(abi::Error::InvalidStatus(status));
+        }
+        // only cancellations have a reason recorded.
+        if !reason.is_empty() && status != abi::ReservationStatus::Cancelled {
+            return Err(abi::Error::InvalidReason(status));
+        }
+        let mut tx = self.pool.begin().await?;
+        let mut builder = select_all(&query, QueryTable::Reservations)?;
+        builder.push(" FOR UPDATE");
+        let matched: Vec<abi::Reservation> = builder.build_query_as().fetch_all(&mut tx).await?;
+
+        let now = Utc::now();
+        let mut rsvps = Vec::new();
+        for rsvp in matched {
+            let current = abi::ReservationStatus::try_from(rsvp.status).unwrap_or_default();
+            // expired holds are left to the reaper, rather than moved along with the others.
+            let expired = current == abi::ReservationStatus::Pending
+                && rsvp
+                    .hold_expires_at
+                    .as_ref()
+                    .is_some_and(|at| timestamp_to_utc_time(at) <= now);
+            if expired || !current.can_transition_to(status) {
+                continue;
+            }
+            let id = Uuid::parse_str(&rsvp.id)
+                .map_err(|_| abi::Error::InvalidReservationId(rsvp.id.clone()))?;
+            rsvps.push(set_status(&mut tx, id, status, abi::SYSTEM_USER_ID, &reason).await?);
+        }
+
+        if dry_run {
+            tx.rollback().await?;
+        } else {
+            tx.commit().await?;
+        }
+
+        Ok(rsvps)
+    }
+
+    async fn update_series(
+        &self,
+        request: abi::UpdateSeriesRequest,
+    ) -> Result<Vec<abi::Reservation>, abi::Error> {
+        request.validate()?;
+        let scope = abi::SeriesScope::try_from(request.scope).unwrap_or_default();
+        let mut tx = self.pool.begin().await?;
+        let (occurrence, mut occurrences) = lock_occurrences(&mut tx, &request.id, scope).await?;
+
+        // the given occurrence is moved to the new time, the others keep their distance to it.
+        let shift = match (request.start.as_ref(), request.end.as_ref()) {
+            (Some(start), Some(end)) => {
+                let old_start = timestamp_to_utc_time(occurrence.start.as_ref().unwrap());
+                let start = timestamp_to_utc_time(start);
+                Some((start - old_start, timestamp_to_utc_time(end) - start))
+            }
+            _ => None,
+        };
+        // occurrences are moved away from the ones not moved yet, so they do not conflict with each other.
+        if shift.is_some_and(|(offset, _)| offset > Duration::zero()) {
+            occurrences.reverse();
+        }
+
+        let mut rsvps = Vec::with_capacity(occurrences.len());
+        let mut conflicts = Vec::new();
+        for occurrence in occurrences {
+            let id = Uuid::parse_str(&occurrence.id)
+                .map_err(|_| abi::Error::InvalidReservationId(occurrence.id.clone()))?;
+            let timespan = shift.map(|(offset, length)| {
+                let start = timestamp_to_utc_time(occurrence.start.as_ref().unwrap()) + offset;
+                PgRange::from(start..start + length)
+            });
+
+            let mut savepoint = tx.begin().await?;
+            let updated = sqlx::query_as(
+                "UPDATE rsvp.reservations SET timespan = COALESCE($2, timespan), note = COALESCE($3, note) WHERE id = $1::uuid RETURNING *",
+            )
+            .bind(id)
+            .bind(timespan)
+            .bind(request.note.as_ref())
+            .fetch_one(&mut savepoint)
+            .await;
+            match updated.map_err(abi::Error::from) {
+                Ok(rsvp) => {
+                    savepoint.commit().await?;
+                    rsvps.push(rsvp);
+                }
+                Err(abi::Error::ConflictReservation(info)) => {
+                    savepoint.rollback().await?;
+                    conflicts.push(info);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        if !conflicts.is_empty() {
+            return Err(abi::Error::ConflictReservations(conflicts));
+        }
+        tx.commit().await?;
+
+        rsvps.sort_by_key(|rsvp: &abi::Reservation| rsvp.start.map(|ts| (ts.seconds, ts.nanos)));
+        Ok(rsvps)
+    }
+
+    async fn cancel_series(
+        &self,
+        id: ReservationId,
+        scope: abi::SeriesScope,
+        cancelled_by: String,
+        reason: String,
+    ) -> Result<Vec<abi::Reservation>, abi::Error> {
+        let mut tx = self.pool.begin().await?;
+        let (_, occurrences) = lock_occurrences(&mut tx, &id, scope).await?;
+
+        let mut rsvps = Vec::with_capacity(occurrences.len());
+        for occurrence in occurrences {
+            let id = Uuid::parse_str(&occurrence.id)
+                .map_err(|_| abi::Error::InvalidReservationId(occurrence.id.clone()))?;
+            let status = abi::ReservationStatus::Cancelled;
+            rsvps.push(set_status(&mut tx, id, status, &cancelled_by, &reason).await?);
+        }
+        tx.commit().await?;
+
+        Ok(rsvps)
+    }
+
+    async fn block(&self, request: abi::BlockRequest) -> Result<abi::BlockResponse, abi::Error> {
+        request.validate()?;
+        let policy = abi::BlockConflictPolicy::try_from(request.on_conflict)
+            .unwrap_or(abi::BlockConflictPolicy::Fail);
+        let block = request.to_reservation();
+        let mut tx = self.pool.begin().await?;
+
+        // expired holds are not reported as overlapping.
+        sqlx::query("SELECT rsvp.expire_holds($1, rsvp.buffered_timespan($1, $2))")
+            .bind(&block.resource_id)
+            .bind(block.get_timespan())
+            .execute(&mut tx)
+            .await?;
+
+        let overlapping: Vec<Uuid> = sqlx::query(
+            "SELECT id FROM rsvp.reservations WHERE resource_id = $1 AND effective_timespan && rsvp.buffered_timespan($1, $2) AND status NOT IN ('CANCELLED', 'REJECTED') AND deleted_at IS NULL ORDER BY lower(timespan) FOR UPDATE",
+        )
+        .bind(&block.resource_id)
+        .bind(block.get_timespan())
+        .fetch_all(&mut tx)
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+        if !overlapping.is_empty() && policy == abi::BlockConflictPolicy::Fail {
+            let ids = overlapping.iter().map(Uuid::to_string).collect();
+            return Err(abi::Error::BlockConflict(ids));
+        }
+
+        let mut cancelled = Vec::with_capacity(overlapping.len());
+        for id in overlapping {
+            let status = abi::ReservationStatus::Cancelled;
+            let rsvp =
+                set_status(&mut tx, id, status, abi::SYSTEM_USER_ID, &request.reason).await?;
+            cancelled.push(rsvp);
+        }
+
+        let block: abi::Reservation = sqlx::query_as(
+            "INSERT INTO rsvp.reservations (user_id, resource_id, timespan, note, status) VALUES ($1, $2, $3, $4, 'BLOCKED') RETURNING *",
+        )
+        .bind(&block.user_id)
+        .bind(&block.resource_id)
+        .bind(block.get_timespan())
+        .bind(&block.note)
+        .fetch_one(&mut tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(abi::BlockResponse {
+            block: Some(block),
+            cancelled,
+        })
+    }
+
+    async fn set_buffers(&self, request: abi::SetBuffersRequest) -> Result<(), abi::Error> {
+        request.validate()?;
+        // the reservations not over yet are widened by a trigger, which fails if they would conflict.
+        // it waits for reservations of the resource being written with the old buffers.
+        sqlx::query(
+            "INSERT INTO rsvp.resource_buffers (resource_id, buffer_before, buffer_after) VALUES ($1, $2 * interval '1 microsecond', $3 * interval '1 microsecond') ON CONFLICT (resource_id) DO UPDATE SET buffer_before = EXCLUDED.buffer_before, buffer_after = EXCLUDED.buffer_after",
+        )
+        .bind(&request.resource_id)
+        .bind(to_micros(request.get_before())?)
+        .bind(to_micros(request.get_after())?)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn availability(
+        &self,
+        request: abi::AvailabilityRequest,
+    ) -> Result<Vec<abi::TimeSlot>, abi::Error> {
+        request.validate()?;
+        let min_duration = to_micros(request.get_min_duration())?;
+        let granularity = request.get_granularity().map(to_micros).transpose()?;
+
+        let slots = sqlx::query(
+            "SELECT lower(slot), upper(slot) FROM rsvp.availability($1, $2, $3 * interval '1 microsecond', $4 * interval '1 microsecond') slot",
+        )
+        .bind(&request.resource_id)
+        .bind(request.get_timespan())
+        .bind(min_duration)
+        .bind(granularity)
+        .fetch_all(&self.pool)
+        .await?
+        .iter()
+        .map(|row| abi::TimeSlot::new(row.get(0), row.get(1)))
+        .collect();
+
+        Ok(slots)
+    }
+
+    async fn find_slot(
+        &self,
+        request: abi::FindSlotRequest,
+    ) -> Result<abi::FindSlotResponse, abi::Error> {
+        request.validate()?;
+        let duration = request.get_duration();
+        let granularity = request.get_granularity().map(to_micros).transpose()?;
+
+        let mut attempts = 0;
+        loop {
+            // candidates are searched in the order given, so earlier ones win a tie.
+            let found = sqlx::query(
+                "SELECT c.rid, lower(slot) FROM unnest($1::text[]) WITH ORDINALITY AS c(rid, pos), LATERAL rsvp.availability(c.rid, $2, $3 * interval '1 microsecond', $4 * interval '1 microsecond') slot ORDER BY lower(slot), c.pos LIMIT 1",
+            )
+            .bind(&request.resource_ids)
+            .bind(request.get_timespan())
+            .bind(to_micros(duration)?)
+            .bind(granularity)
+            .fetch_optional(&self.pool)
+            .await?;
+            let Some(row) = found else {
+                return Ok(abi::FindSlotResponse::default());
+            };
+            let resource_id: String = row.get(0);
+            let start: DateTime<Utc> = row.get(1);
+            let slot = abi::TimeSlot::new(start, start + duration);
+
+            let Some(template) = request.reservation.clone() else {
+                return Ok(abi::FindSlotResponse {
+                    resource_id,
+                    slot: Some(slot),
+                    reservation: None,
+                });
+            };
+            let rsvp = abi::Reservation {
+                resource_id: resource_id.clone(),
+                start: slot.start,
+                end: slot.end,
+                ..template
+            };
+            // the exclusion constraint rejects the slot if a concurrent caller took it first, then
+            // the search starts over.
+            match self.reserve(rsvp).await {
+                Ok(rsvp) => {
+                    return Ok(abi::FindSlotResponse {
+                        resource_id,
+                        slot: Some(slot),
+                        reservation: Some(rsvp),
+                    });
+                }
+                Err(abi::Error::ConflictReservation(_)) if attempts < FIND_SLOT_RETRIES => {
+                    attempts += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn expire_holds(&self) -> Result<u64, abi::Error> {
+        let expired: i64 = sqlx::query("SELECT rsvp.expire_holds()")
+            .fetch_one(&self.pool)
             .await?
-            .rows_affected();
+            .get(0);
+
+        Ok(expired as u64)
+    }
+
+    async fn delete(&self, id: ReservationId) -> Result<(), abi::Error> {
+        let id = Uuid::parse_str(&id).map_err(|_| abi::Error::InvalidReservationId(id.clone()))?;
+        // Mark the reservation as deleted, it is moved out of the table once archived.
+        let rows_affected = sqlx::query(
+            "UPDATE rsvp.reservations SET deleted_at = now() WHERE id = $1::uuid AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(abi::Error::NotFound);
+        }
 
         Ok(())
     }
@@ -72,41 +601,99 @@ impl Rsvp for ReservationManager {
     async fn get(&self, id: ReservationId) -> Result<abi::Reservation, abi::Error> {
         let id = Uuid::parse_str(&id).map_err(|_| abi::Error::InvalidReservationId(id.clone()))?;
         // Execute the SQL query to get the reservation by ID.
-        let rsvp: abi::Reservation =
-            sqlx::query_as("SELECT * FROM rsvp.reservations WHERE id = $1::uuid")
-                .bind(id)
-                .fetch_one(&self.pool)
-                .await?;
+        let rsvp: abi::Reservation = sqlx::query_as(
+            "SELECT * FROM rsvp.reservations WHERE id = $1::uuid AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .fetch_one(&self.pool)
+        .await?;
 
         Ok(rsvp)
     }
 
-    async fn query(
+    async fn query(&self, query: abi::ReservationQuery) -> Result<abi::QueryResult, abi::Error> {
+        query.validate()?;
+        // one more row tells whether there are more pages.
+        let rsvps = select_page(&query, QueryTable::Reservations, query.get_page_size() + 1)?
+            .build_query_as()
+            .fetch_all(&self.pool)
+            .await?;
+
+        let total_count = if query.with_total_count {
+            let count: i64 = select_count(&query, QueryTable::Reservations)?
+                .build()
+                .fetch_one(&self.pool)
+                .await?
+                .get(0);
+            Some(count)
+        } else {
+            None
+        };
+
+        abi::QueryResult::new(&query, rsvps, total_count)
+    }
+
+    async fn query_stream(
         &self,
         query: abi::ReservationQuery,
-    ) -> Result<Vec<abi::Reservation>, abi::Error> {
-        let user_id = str_to_option(&query.user_id);
-        let resource_id = str_to_option(&query.resource_id);
-        let range = query.get_timespan();
-        let status = abi::ReservationStatus::try_from(query.status)
-            .unwrap_or(abi::ReservationStatus::Pending);
-        let rsvps = sqlx::query_as(
-            "SELECt * from rsvp.query($1, $2, $3, $4::rsvp.reservation_status, $5, $6, $7)",
+    ) -> Result<BoxStream<'static, Result<abi::Reservation, abi::Error>>, abi::Error> {
+        query.validate()?;
+        let mut builder = select_all(&query, QueryTable::Reservations)?;
+        let pool = self.pool.clone();
+
+        // rows are read by a task feeding a bounded channel, so reading waits for the consumer and
+        // stops once the stream is dropped.
+        let (tx, rx) = mpsc::channel(STREAM_BUFFER);
+        tokio::spawn(async move {
+            let mut rows = builder.build_query_as::<abi::Reservation>().fetch(&pool);
+            while let Some(row) = rows.next().await {
+                let failed = row.is_err();
+                if tx.send(row.map_err(abi::Error::from)).await.is_err() || failed {
+                    break;
+                }
+            }
+        });
+
+        Ok(futures::stream::unfold(rx, |mut rx| async move {
+            rx.recv().await.map(|rsvp| (rsvp, rx))
+        })
+        .boxed())
+    }
+
+    async fn archive(&self, max_age: Duration) -> Result<u64, abi::Error> {
+        let before = Utc::now() - max_age;
+        // the archive is filled by column name, so it does not depend on the column order of both tables.
+        let rows_affected = sqlx::query(
+            "WITH archived AS (DELETE FROM rsvp.reservations WHERE upper(timespan) < $1 OR cancelled_at < $1 OR deleted_at < $1 RETURNING *) INSERT INTO rsvp.reservations_archive SELECT (jsonb_populate_record(NULL::rsvp.reservations_archive, to_jsonb(a) || jsonb_build_object('archived_at', now()))).* FROM archived a",
         )
-        .bind(user_id)
-        .bind(resource_id)
-        .bind(range)
-        .bind(status.to_string())
-        .bind(query.page_size)
-        .bind(query.page)
-        .bind(query.desc)
-        .fetch_all(&self.pool)
-        .await?;
+        .bind(before)
+        .execute(&self.pool)
+        .await?
+        .rows_affected();
+
+        Ok(rows_affected)
+    }
+
+    async fn query_archive(
+        &self,
+        query: abi::ReservationQuery,
+    ) -> Result<Vec<abi::Reservation>, abi::Error> {
+        query.validate()?;
+        let rsvps = select_page(&query, QueryTable::Archive, query.get_page_size())?
+            .build_query_as()
+            .fetch_all(&self.pool)
+            .await?;
 
         Ok(rsvps)
     }
 }
 
+fn to_micros(duration: Duration) -> Result<i64, abi::Error> {
+    duration
+        .num_microseconds()
+        .ok_or_else(|| abi::Error::InvalidDuration(duration.to_string()))
+}
+
 fn str_to_option(s: &str) -> Option<&str> {
     if s.is_empty() { None } else { Some(s) }
 }
@@ -115,6 +702,251 @@ impl ReservationManager {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Moves the reservation to `status` if the transition is legal. Cancellation details are
+    /// recorded when the reservation is cancelled.
+    async fn update_status(
+        &self,
+        id: ReservationId,
+        status: abi::ReservationStatus,
+        cancelled_by: &str,
+        reason: &str,
+    ) -> Result<abi::Reservation, abi::Error> {
+        let id = Uuid::parse_str(&id).map_err(|_| abi::Error::InvalidReservationId(id.clone()))?;
+        // an expired hold is cancelled for good, even if the transition below fails.
+        sqlx::query("SELECT rsvp.expire_holds(r_id => $1)")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        let mut tx = self.pool.begin().await?;
+        let rsvp = set_status(&mut tx, id, status, cancelled_by, reason).await?;
+        tx.commit().await?;
+
+        Ok(rsvp)
+    }
+}
+
+/// Validates a reservation made by a user.
+fn check_reservation(rsvp: &abi::Reservation) -> Result<(), abi::Error> {
+    // Validate the reservation.
+    rsvp.validate()?;
+    // system reservations could only be made through dedicated apis, e.g. `block`.
+    if rsvp.user_id == abi::SYSTEM_USER_ID {
+        return Err(abi::Error::InvalidUserId(rsvp.user_id.clone()));
+    }
+    // reservations are always made pending, other statuses are reached through transitions.
+    let status = abi::ReservationStatus::try_from(rsvp.status).unwrap_or_default();
+    if rsvp.status != abi::ReservationStatus::Unknown as i32
+        && status != abi::ReservationStatus::Pending
+    {
+        return Err(abi::Error::InvalidStatus(status));
+    }
+
+    Ok(())
+}
+
+/// Validates and inserts the reservation, returning it as stored.
+async fn insert_reservation(
+    conn: &mut PgConnection,
+    rsvp: abi::Reservation,
+    series_id: Option<Uuid>,
+    group_id: Option<Uuid>,
+) -> Result<abi::Reservation, abi::Error> {
+    check_reservation(&rsvp)?;
+
+    // Convert the start and end times to UTC.
+    let timespan = rsvp.get_timespan();
+
+    let hold_expires_at = rsvp
+        .hold_expires_at
+        .as_ref()
+        .map(abi::timestamp_to_utc_time);
+
+    // execute the SQL query to insert the reservation and return it as stored.
+    let rsvp = sqlx::query_as("INSERT INTO rsvp.reservations (user_id, resource_id, timespan, note, status, hold_expires_at, series_id, group_id) VALUES ($1, $2, $3, $4, 'PENDING', $5, $6, $7) RETURNING *")
+        .bind(rsvp.user_id)
+        .bind(rsvp.resource_id)
+        .bind(timespan)
+        .bind(rsvp.note)
+        .bind(hold_expires_at)
+        .bind(series_id)
+        .bind(group_id)
+        .fetch_one(conn)
+        .await?;
+
+    Ok(rsvp)
+}
+
+/// Inserts every reservation within the transaction. Each one is inserted in a savepoint, so all
+/// conflicts are found before giving up, the caller must roll back if any reservation failed.
+async fn insert_all(
+    tx: &mut Transaction<'_, Postgres>,
+    rsvps: Vec<abi::Reservation>,
+    series_id: Option<Uuid>,
+    group_id: Option<Uuid>,
+) -> Result<Vec<abi::Reservation>, abi::Error> {
+    let mut inserted = Vec::with_capacity(rsvps.len());
+    let mut conflicts = Vec::new();
+    for rsvp in rsvps {
+        let mut savepoint = tx.begin().await?;
+        match insert_reservation(&mut savepoint, rsvp, series_id, group_id).await {
+            Ok(rsvp) => {
+                savepoint.commit().await?;
+                inserted.push(rsvp);
+            }
+            Err(abi::Error::ConflictReservation(info)) => {
+                savepoint.rollback().await?;
+                conflicts.push(info);
+            }
+            Err(e) => return Err(e),
+        }
+    }
+    if !conflicts.is_empty() {
+        return Err(abi::Error::ConflictReservations(conflicts));
+    }
+
+    Ok(inserted)
+}
+
+/// Inserts validated reservations with one statement, returning them as stored in the same order.
+async fn insert_batch(
+    conn: &mut PgConnection,
+    rsvps: &[(usize, abi::Reservation)],
+) -> Result<Vec<abi::Reservation>, abi::Error> {
+    // the order of RETURNING rows is not guaranteed, so ids are picked up front to match them by.
+    let ids: Vec<Uuid> = sqlx::query("SELECT gen_random_uuid() FROM generate_series(1, $1)")
+        .bind(rsvps.len() as i32)
+        .fetch_all(&mut *conn)
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+
+    let mut builder = QueryBuilder::new(
+        "INSERT INTO rsvp.reservations (id, user_id, resource_id, timespan, note, hold_expires_at) ",
+    );
+    builder.push_values(rsvps.iter().zip(&ids), |mut row, ((_, rsvp), id)| {
+        row.push_bind(*id)
+            .push_bind(rsvp.user_id.clone())
+            .push_bind(rsvp.resource_id.clone())
+            .push_bind(rsvp.get_timespan())
+            .push_bind(rsvp.note.clone())
+            .push_bind(
+                rsvp.hold_expires_at
+                    .as_ref()
+                    .map(abi::timestamp_to_utc_time),
+            );
+    });
+    builder.push(" RETURNING *");
+
+    let mut created: HashMap<String, abi::Reservation> = builder
+        .build_query_as::<abi::Reservation>()
+        .fetch_all(&mut *conn)
+        .await?
+        .into_iter()
+        .map(|rsvp| (rsvp.id.clone(), rsvp))
+        .collect();
+    ids.iter()
+        .map(|id| created.remove(&id.to_string()).ok_or(abi::Error::Unknown))
+        .collect()
+}
+
+/// Locks the reservations of the group, ordered by start time.
+async fn lock_group(
+    tx: &mut Transaction<'_, Postgres>,
+    group_id: Uuid,
+) -> Result<Vec<abi::Reservation>, abi::Error> {
+    let rsvps: Vec<abi::Reservation> = sqlx::query_as(
+        "SELECT * FROM rsvp.reservations WHERE group_id = $1 AND deleted_at IS NULL ORDER BY lower(timespan), id FOR UPDATE",
+    )
+    .bind(group_id)
+    .fetch_all(&mut *tx)
+    .await?;
+    if rsvps.is_empty() {
+        return Err(abi::Error::NotFound);
+    }
+
+    Ok(rsvps)
+}
+
+fn parse_group_id(group_id: &str) -> Result<Uuid, abi::Error> {
+    Uuid::parse_str(group_id).map_err(|_| abi::Error::InvalidGroupId(group_id.to_string()))
+}
+
+/// Locks the given occurrence and the occurrences in scope of its series which are neither cancelled
+/// nor rejected, ordered by start time. A reservation not made as a series is a series of its own.
+async fn lock_occurrences(
+    tx: &mut Transaction<'_, Postgres>,
+    id: &str,
+    scope: abi::SeriesScope,
+) -> Result<(abi::Reservation, Vec<abi::Reservation>), abi::Error> {
+    let id = Uuid::parse_str(id).map_err(|_| abi::Error::InvalidReservationId(id.to_string()))?;
+    let occurrence: abi::Reservation = sqlx::query_as(
+        "SELECT * FROM rsvp.reservations WHERE id = $1::uuid AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await?;
+    let status = abi::ReservationStatus::try_from(occurrence.status).unwrap_or_default();
+    if status.is_final() {
+        return Err(abi::Error::FinalStatus(status));
+    }
+
+    let series_id = Uuid::parse_str(&occurrence.series_id).ok();
+    let mut builder = QueryBuilder::new(
+        "SELECT * FROM rsvp.reservations WHERE deleted_at IS NULL AND status NOT IN ('CANCELLED', 'REJECTED')",
+    );
+    match (scope, series_id) {
+        (abi::SeriesScope::Occurrence, _) | (_, None) => {
+            builder.push(" AND id = ").push_bind(id);
+        }
+        (abi::SeriesScope::Following, Some(series_id)) => {
+            builder
+                .push(" AND series_id = ")
+                .push_bind(series_id)
+                .push(" AND lower(timespan) >= ")
+                .push_bind(occurrence.start.as_ref().map(timestamp_to_utc_time));
+        }
+        (abi::SeriesScope::All, Some(series_id)) => {
+            builder.push(" AND series_id = ").push_bind(series_id);
+        }
+    }
+    builder.push(" ORDER BY lower(timespan) FOR UPDATE");
+    let occurrences = builder.build_query_as().fetch_all(&mut *tx).await?;
+
+    Ok((occurrence, occurrences))
+}
+
+/// Moves the reservation to `status` within the transaction, see `ReservationManager::update_status`.
+async fn set_status(
+    tx: &mut Transaction<'_, Postgres>,
+    id: Uuid,
+    status: abi::ReservationStatus,
+    cancelled_by: &str,
+    reason: &str,
+) -> Result<abi::Reservation, abi::Error> {
+    // lock the reservation, so the status could not change between the check and the update.
+    let current: abi::RsvpStatus = sqlx::query(
+        "SELECT status FROM rsvp.reservations WHERE id = $1::uuid AND deleted_at IS NULL FOR UPDATE",
+    )
+    .bind(id)
+    .fetch_one(&mut *tx)
+    .await?
+    .get(0);
+    let status = abi::ReservationStatus::from(current).transition_to(status)?;
+
+    let rsvp = sqlx::query_as(
+        "UPDATE rsvp.reservations SET status = $2::rsvp.reservation_status, cancelled_at = CASE WHEN $2 = 'CANCELLED' THEN now() ELSE cancelled_at END, cancelled_by = CASE WHEN $2 = 'CANCELLED' THEN $3 ELSE cancelled_by END, cancel_reason = CASE WHEN $2 = 'CANCELLED' THEN $4 ELSE cancel_reason END WHERE id = $1::uuid RETURNING *",
+    )
+    .bind(id)
+    .bind(status.to_string())
+    .bind(str_to_option(cancelled_by))
+    .bind(str_to_option(reason))
+    .fetch_one(&mut *tx)
+    .await?;
+
+    Ok(rsvp)
 }
 
 #[cfg(test)]
@@ -163,77 +995,1540 @@ mod tests {
                 start: "2025-06-01 19:00:00 UTC".parse().unwrap(),
                 end: "2025-06-03 19:00:00 UTC".parse().unwrap(),
             },
+            buffered: false,
         });
 
         assert_eq!(err, abi::Error::ConflictReservation(info));
     }
 
     #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
-    async fn change_status_should_work() {
+    async fn import_should_report_every_row() {
         let manager = ReservationManager::new(migrated_pool.clone());
-        let rsvp = make_basic_reservation(&manager).await.unwrap();
-        assert_eq!(rsvp.status, abi::ReservationStatus::Pending as i32);
-        let updated_rsvp = manager.change_status(rsvp.id.clone()).await.unwrap();
+        let existing = make_basic_reservation(&manager).await.unwrap();
+        let rows = make_import_rows(&existing);
+
+        let report = manager
+            .import(rows.clone(), abi::ImportOptions::default())
+            .await
+            .unwrap();
+        assert!(report.committed);
+        assert_eq!((report.created(), report.failed()), (2, 3));
+        let abi::ImportOutcome::Created(created) = &report.rows[0] else {
+            panic!(
+                "expected the first row to be created, got {:?}",
+                report.rows[0]
+            );
+        };
+        assert_eq!(manager.get(created.id.clone()).await.unwrap(), **created);
         assert_eq!(
-            updated_rsvp.status,
-            abi::ReservationStatus::Confirmed as i32
+            report.rows[1],
+            abi::ImportOutcome::Invalid(abi::Error::InvalidUserId("".to_string()))
         );
-        assert_eq!(updated_rsvp.id, rsvp.id);
+        let abi::ImportOutcome::Conflict(ReservationConflictInfo::Parsed(conflict)) =
+            &report.rows[2]
+        else {
+            panic!("expected a conflict, got {:?}", report.rows[2]);
+        };
+        assert_eq!(conflict.old.rid, existing.resource_id);
+        assert!(matches!(report.rows[3], abi::ImportOutcome::Created(_)));
+        // conflicts with the first row of the import.
+        assert!(matches!(report.rows[4], abi::ImportOutcome::Conflict(_)));
     }
 
     #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
-    async fn change_status_on_no_pending_rsvp_should_do_nothing() {
+    async fn import_should_roll_back_dry_run_and_failed_all_or_nothing() {
         let manager = ReservationManager::new(migrated_pool.clone());
-        let rsvp = make_basic_reservation(&manager).await.unwrap();
-        assert_eq!(rsvp.status, abi::ReservationStatus::Pending as i32);
-        let updated_rsvp = manager.change_status(rsvp.id.clone()).await.unwrap();
-        assert_eq!(
-            updated_rsvp.status,
-            abi::ReservationStatus::Confirmed as i32
-        );
-        let res = manager.change_status(rsvp.id.clone()).await.unwrap_err();
-        assert_eq!(res, abi::Error::NotFound);
+        let existing = make_basic_reservation(&manager).await.unwrap();
+        let rows = make_import_rows(&existing);
+        let query = ReservationQueryBuilder::default()
+            .user_id("gigi")
+            .build()
+            .unwrap();
+
+        let options = abi::ImportOptions {
+            policy: abi::ImportPolicy::AllOrNothing,
+            dry_run: false,
+        };
+        let report = manager.import(rows.clone(), options).await.unwrap();
+        assert!(!report.committed);
+        assert_eq!(report.created(), 2);
+        assert!(manager.query(query.clone()).await.unwrap().items.is_empty());
+
+        // a dry run reports the same as a real import.
+        let options = abi::ImportOptions {
+            dry_run: true,
+            ..Default::default()
+        };
+        let report = manager.import(rows, options).await.unwrap();
+        assert!(!report.committed);
+        assert_eq!((report.created(), report.failed()), (2, 3));
+        assert!(manager.query(query).await.unwrap().items.is_empty());
     }
 
     #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
-    async fn update_note_should_work() {
+    async fn import_should_keep_row_order_across_batches() {
         let manager = ReservationManager::new(migrated_pool.clone());
-        let rsvp = make_basic_reservation(&manager).await.unwrap();
-        assert!(!rsvp.id.is_empty());
+        let start: DateTime<FixedOffset> = "2025-06-01T00:00:00Z".parse().unwrap();
+        let rows: Vec<_> = (0..1200)
+            .map(|i| {
+                let start = start + Duration::hours(i);
+                abi::Reservation::new_pending(
+                    "gigi",
+                    "room-114514",
+                    start,
+                    start + Duration::hours(1),
+                    "",
+                )
+            })
+            .collect();
 
-        let new_note = "Mamba out!".to_string();
-        let updated_rsvp = manager
-            .update_note(rsvp.id.clone(), new_note.clone())
+        let report = manager
+            .import(rows.clone(), abi::ImportOptions::default())
             .await
             .unwrap();
-        assert_eq!(updated_rsvp.note, new_note);
+        assert_eq!(report.created(), 1200);
+        for (row, outcome) in rows.iter().zip(&report.rows) {
+            let abi::ImportOutcome::Created(created) = outcome else {
+                panic!("expected the row to be created, got {outcome:?}");
+            };
+            assert_eq!(created.start, row.start);
+        }
     }
 
     #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
-    async fn delete_should_work() {
+    async fn reserve_many_should_share_group() {
         let manager = ReservationManager::new(migrated_pool.clone());
-        let rsvp = make_basic_reservation(&manager).await.unwrap();
-        assert!(!rsvp.id.is_empty());
-
-        // Delete the reservation.
-        manager.delete(rsvp.id.clone()).await.unwrap();
+        let rsvps = manager.reserve_many(make_bundle()).await.unwrap();
 
-        // Try to get the deleted reservation, should return NotFound error.
-        let res = manager.get(rsvp.id.clone()).await;
-        assert!(res.is_err());
-        assert_eq!(res.unwrap_err(), abi::Error::NotFound);
+        assert_eq!(rsvps.len(), 3);
+        let rids: Vec<_> = rsvps.iter().map(|rsvp| rsvp.resource_id.as_str()).collect();
+        assert_eq!(rids, ["room-114514", "projector-1", "catering"]);
+        assert!(!rsvps[0].group_id.is_empty());
+        assert!(rsvps.iter().all(|rsvp| rsvp.group_id == rsvps[0].group_id));
     }
 
     #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
-    async fn get_should_work() {
+    async fn reserve_many_should_report_every_conflict() {
         let manager = ReservationManager::new(migrated_pool.clone());
-        let rsvp = make_basic_reservation(&manager).await.unwrap();
-        assert!(!rsvp.id.is_empty());
+        make_basic_reservation(&manager).await.unwrap();
+        let mut bundle = make_bundle();
+        // conflicts with the room of the bundle itself.
+        bundle.push(abi::Reservation {
+            note: "double booked".to_string(),
+            ..bundle[0].clone()
+        });
 
-        // Query reservations by room ID.
-        let get = manager.get(rsvp.id.clone()).await.unwrap();
-        assert_eq!(rsvp, get);
-    }
+        let err = manager.reserve_many(bundle).await.unwrap_err();
+        let abi::Error::ConflictReservations(conflicts) = err else {
+            panic!("expected conflicts, got {err:?}");
+        };
+        assert_eq!(conflicts.len(), 2);
+        assert!(conflicts.iter().all(|info| matches!(
+            info,
+            ReservationConflictInfo::Parsed(conflict) if conflict.new.rid == "room-114514"
+        )));
+
+        // nothing is reserved, the projector is still free.
+        let query = ReservationQueryBuilder::default()
+            .resource_id("projector-1")
+            .build()
+            .unwrap();
+        assert!(manager.query(query).await.unwrap().items.is_empty());
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn group_should_be_confirmed_and_cancelled_together() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvps = manager.reserve_many(make_bundle()).await.unwrap();
+        let group_id = rsvps[0].group_id.clone();
+        manager.change_status(rsvps[1].id.clone()).await.unwrap();
+
+        let confirmed = manager.confirm_group(group_id.clone()).await.unwrap();
+        assert_eq!(confirmed.len(), 3);
+        assert!(
+            confirmed
+                .iter()
+                .all(|rsvp| rsvp.status == abi::ReservationStatus::Confirmed as i32)
+        );
+
+        manager
+            .cancel(rsvps[2].id.clone(), "kobe".to_string(), "".to_string())
+            .await
+            .unwrap();
+        let cancelled = manager
+            .cancel_group(
+                group_id.clone(),
+                "kobe".to_string(),
+                "called off".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(cancelled.len(), 2);
+        assert_eq!(
+            cancelled[0].cancellation.as_ref().unwrap().reason,
+            "called off"
+        );
+
+        let err = manager.confirm_group(group_id).await.unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::FinalStatus(abi::ReservationStatus::Cancelled)
+        );
+        let err = manager
+            .confirm_group("not-a-group".to_string())
+            .await
+            .unwrap_err();
+        assert_eq!(err, abi::Error::InvalidGroupId("not-a-group".to_string()));
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn bulk_transition_should_cancel_every_match() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let pending = make_basic_reservation(&manager).await.unwrap();
+        let confirmed = make_reservation(
+            &manager,
+            "gigi",
+            "room-114514",
+            "2025-06-05T12:00:00-07:00".parse().unwrap(),
+            "2025-06-06T12:00:00-07:00".parse().unwrap(),
+            "",
+        )
+        .await
+        .unwrap();
+        manager.change_status(confirmed.id.clone()).await.unwrap();
+        let cancelled = make_reservation(
+            &manager,
+            "gigi",
+            "room-114514",
+            "2025-06-07T12:00:00-07:00".parse().unwrap(),
+            "2025-06-08T12:00:00-07:00".parse().unwrap(),
+            "",
+        )
+        .await
+        .unwrap();
+        manager
+            .cancel(cancelled.id.clone(), "gigi".to_string(), "".to_string())
+            .await
+            .unwrap();
+        let later = make_reservation(
+            &manager,
+            "kobe",
+            "room-114514",
+            "2025-07-01T12:00:00-07:00".parse().unwrap(),
+            "2025-07-02T12:00:00-07:00".parse().unwrap(),
+            "",
+        )
+        .await
+        .unwrap();
+        let count_changes = || async {
+            let count: i64 = sqlx::query("SELECT count(*) FROM rsvp.reservations_changes")
+                .fetch_one(&migrated_pool)
+                .await
+                .unwrap()
+                .get(0);
+            count
+        };
+        let changes = count_changes().await;
+
+        let query = ReservationQueryBuilder::default()
+            .resource_id("room-114514")
+            .start("2025-06-01T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+            .end("2025-06-10T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let status = abi::ReservationStatus::Cancelled;
+        let reason = "closed for the holiday".to_string();
+
+        let preview = manager
+            .bulk_transition(query.clone(), status, reason.clone(), true)
+            .await
+            .unwrap();
+        let ids: Vec<_> = preview.iter().map(|rsvp| rsvp.id.clone()).collect();
+        assert_eq!(ids, [pending.id.clone(), confirmed.id.clone()]);
+        assert_eq!(count_changes().await, changes);
+        assert_eq!(manager.get(pending.id.clone()).await.unwrap(), pending);
+
+        let rsvps = manager
+            .bulk_transition(query, status, reason.clone(), false)
+            .await
+            .unwrap();
+        assert_eq!(rsvps.len(), 2);
+        for rsvp in &rsvps {
+            assert_eq!(rsvp.status, status as i32);
+            let cancellation = rsvp.cancellation.as_ref().unwrap();
+            assert_eq!(cancellation.cancelled_by, abi::SYSTEM_USER_ID);
+            assert_eq!(cancellation.reason, reason);
+        }
+        assert_eq!(count_changes().await, changes + 2);
+        assert_eq!(manager.get(later.id.clone()).await.unwrap(), later);
+
+        // a reason could not be recorded for a rejection.
+        let query = ReservationQueryBuilder::default()
+            .resource_id("room-114514")
+            .build()
+            .unwrap();
+        let err = manager
+            .bulk_transition(
+                query.clone(),
+                abi::ReservationStatus::Rejected,
+                reason.clone(),
+                false,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::InvalidReason(abi::ReservationStatus::Rejected)
+        );
+        assert_eq!(manager.get(later.id.clone()).await.unwrap(), later);
+
+        // cancelled reservations are not brought back as blocks.
+        let err = manager
+            .bulk_transition(query, abi::ReservationStatus::Blocked, reason, false)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::InvalidStatus(abi::ReservationStatus::Blocked)
+        );
+        assert_eq!(manager.get(rsvps[0].id.clone()).await.unwrap(), rsvps[0]);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn bulk_transition_should_skip_expired_holds() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let expired = make_hold(&manager, "room-1", "2025-06-01T00:00:00Z").await;
+        let held = make_hold(&manager, "room-2", "2100-01-01T00:00:00Z").await;
+        let query = ReservationQueryBuilder::default()
+            .user_id("kobe")
+            .build()
+            .unwrap();
+        let status = abi::ReservationStatus::Confirmed;
+
+        for dry_run in [true, false] {
+            let rsvps = manager
+                .bulk_transition(query.clone(), status, "".to_string(), dry_run)
+                .await
+                .unwrap();
+            let ids: Vec<_> = rsvps.iter().map(|rsvp| rsvp.id.clone()).collect();
+            assert_eq!(ids, [held.id.as_str()]);
+            // the expired hold is not touched, not even cancelled.
+            assert_eq!(manager.get(expired.id.clone()).await.unwrap(), expired);
+        }
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn reserve_series_should_create_occurrences() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvps = make_series(&manager, "FREQ=WEEKLY;BYDAY=MO,WE;COUNT=4").await;
+
+        let starts: Vec<_> = rsvps.iter().map(|rsvp| rsvp.start.unwrap()).collect();
+        assert_eq!(
+            starts,
+            [
+                "2025-06-02T09:00:00Z".parse().unwrap(),
+                "2025-06-04T09:00:00Z".parse().unwrap(),
+                "2025-06-09T09:00:00Z".parse().unwrap(),
+                "2025-06-11T09:00:00Z".parse::<abi::Timestamp>().unwrap(),
+            ]
+        );
+        assert!(!rsvps[0].series_id.is_empty());
+        assert!(
+            rsvps
+                .iter()
+                .all(|rsvp| rsvp.series_id == rsvps[0].series_id)
+        );
+        assert_eq!(manager.get(rsvps[3].id.clone()).await.unwrap(), rsvps[3]);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn reserve_series_should_report_every_conflict() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        for day in ["2025-06-04", "2025-06-11"] {
+            make_reservation(
+                &manager,
+                "gigi",
+                "room-114514",
+                format!("{day}T09:30:00Z").parse().unwrap(),
+                format!("{day}T10:30:00Z").parse().unwrap(),
+                "",
+            )
+            .await
+            .unwrap();
+        }
+
+        let err = manager
+            .reserve_series(
+                make_series_rsvp(),
+                make_recurrence("FREQ=WEEKLY;BYDAY=MO,WE;COUNT=4"),
+            )
+            .await
+            .unwrap_err();
+        let abi::Error::ConflictReservations(conflicts) = err else {
+            panic!("expected conflicts, got {err:?}");
+        };
+        let starts: Vec<_> = conflicts
+            .iter()
+            .map(|info| match info {
+                ReservationConflictInfo::Parsed(conflict) => conflict.new.start.to_rfc3339(),
+                ReservationConflictInfo::Unparsed(info) => info.clone(),
+            })
+            .collect();
+        assert_eq!(
+            starts,
+            ["2025-06-04T09:00:00+00:00", "2025-06-11T09:00:00+00:00"]
+        );
+
+        // nothing is reserved.
+        let query = ReservationQueryBuilder::default()
+            .user_id("kobe")
+            .build()
+            .unwrap();
+        assert!(manager.query(query).await.unwrap().items.is_empty());
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn update_series_should_follow_scope() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvps = make_series(&manager, "FREQ=DAILY;COUNT=4").await;
+        let request = |id: &str, scope: abi::SeriesScope| abi::UpdateSeriesRequest {
+            id: id.to_string(),
+            scope: scope as i32,
+            ..Default::default()
+        };
+
+        let updated = manager
+            .update_series(abi::UpdateSeriesRequest {
+                note: Some("retro".to_string()),
+                ..request(&rsvps[2].id, abi::SeriesScope::Following)
+            })
+            .await
+            .unwrap();
+        assert_eq!(updated.len(), 2);
+        assert_eq!(updated[0].id, rsvps[2].id);
+        assert!(updated.iter().all(|rsvp| rsvp.note == "retro"));
+        assert_eq!(
+            manager.get(rsvps[1].id.clone()).await.unwrap().note,
+            "standup"
+        );
+
+        // moved a day later, each occurrence takes the time of the next one.
+        let updated = manager
+            .update_series(abi::UpdateSeriesRequest {
+                start: Some("2025-06-04T09:00:00Z".parse().unwrap()),
+                end: Some("2025-06-04T09:15:00Z".parse().unwrap()),
+                ..request(&rsvps[1].id, abi::SeriesScope::All)
+            })
+            .await
+            .unwrap();
+        let starts: Vec<_> = updated.iter().map(|rsvp| rsvp.start.unwrap()).collect();
+        let ends: Vec<_> = updated.iter().map(|rsvp| rsvp.end.unwrap()).collect();
+        assert_eq!(
+            starts,
+            rsvps[1..]
+                .iter()
+                .map(|rsvp| rsvp.start.unwrap())
+                .chain(Some("2025-06-06T09:00:00Z".parse().unwrap()))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(ends[0], "2025-06-03T09:15:00Z".parse().unwrap());
+
+        // the occurrence only.
+        let updated = manager
+            .update_series(abi::UpdateSeriesRequest {
+                start: Some("2025-06-03T14:00:00Z".parse().unwrap()),
+                end: Some("2025-06-03T15:00:00Z".parse().unwrap()),
+                ..request(&rsvps[0].id, abi::SeriesScope::Occurrence)
+            })
+            .await
+            .unwrap();
+        assert_eq!(updated.len(), 1);
+        assert_eq!(
+            updated[0].start,
+            Some("2025-06-03T14:00:00Z".parse().unwrap())
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn cancel_series_should_follow_scope() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvps = make_series(&manager, "FREQ=DAILY;COUNT=4").await;
+        let cancel = |id: &str, scope: abi::SeriesScope| {
+            manager.cancel_series(
+                id.to_string(),
+                scope,
+                "kobe".to_string(),
+                "holiday".to_string(),
+            )
+        };
+
+        let cancelled = cancel(&rsvps[1].id, abi::SeriesScope::Occurrence)
+            .await
+            .unwrap();
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(
+            cancelled[0].status,
+            abi::ReservationStatus::Cancelled as i32
+        );
+        assert_eq!(
+            cancelled[0].cancellation.as_ref().unwrap().reason,
+            "holiday"
+        );
+
+        let cancelled = cancel(&rsvps[2].id, abi::SeriesScope::Following)
+            .await
+            .unwrap();
+        let ids: Vec<_> = cancelled.iter().map(|rsvp| rsvp.id.clone()).collect();
+        assert_eq!(ids, [rsvps[2].id.clone(), rsvps[3].id.clone()]);
+
+        // cancelled occurrences are skipped.
+        let cancelled = cancel(&rsvps[0].id, abi::SeriesScope::All).await.unwrap();
+        assert_eq!(cancelled.len(), 1);
+        assert_eq!(cancelled[0].id, rsvps[0].id);
+
+        let err = cancel(&rsvps[0].id, abi::SeriesScope::All)
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::FinalStatus(abi::ReservationStatus::Cancelled)
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn change_status_should_work() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        assert_eq!(rsvp.status, abi::ReservationStatus::Pending as i32);
+        let updated_rsvp = manager.change_status(rsvp.id.clone()).await.unwrap();
+        assert_eq!(
+            updated_rsvp.status,
+            abi::ReservationStatus::Confirmed as i32
+        );
+        assert_eq!(updated_rsvp.id, rsvp.id);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn change_status_on_no_pending_rsvp_should_reject() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        assert_eq!(rsvp.status, abi::ReservationStatus::Pending as i32);
+        let updated_rsvp = manager.change_status(rsvp.id.clone()).await.unwrap();
+        assert_eq!(
+            updated_rsvp.status,
+            abi::ReservationStatus::Confirmed as i32
+        );
+        let res = manager.change_status(rsvp.id.clone()).await.unwrap_err();
+        assert_eq!(
+            res,
+            abi::Error::InvalidTransition {
+                from: abi::ReservationStatus::Confirmed,
+                to: abi::ReservationStatus::Confirmed,
+            }
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn transition_should_follow_state_machine() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+
+        let rejected = manager
+            .transition(rsvp.id.clone(), abi::ReservationStatus::Rejected)
+            .await
+            .unwrap();
+        assert_eq!(rejected.status, abi::ReservationStatus::Rejected as i32);
+        assert_eq!(rejected.cancellation, None);
+
+        let res = manager
+            .transition(rsvp.id.clone(), abi::ReservationStatus::Confirmed)
+            .await;
+        assert_eq!(
+            res.unwrap_err(),
+            abi::Error::InvalidTransition {
+                from: abi::ReservationStatus::Rejected,
+                to: abi::ReservationStatus::Confirmed,
+            }
+        );
+
+        // rejected reservation no longer blocks the resource.
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        let res = manager
+            .transition(rsvp.id.clone(), abi::ReservationStatus::Blocked)
+            .await;
+        assert_eq!(
+            res.unwrap_err(),
+            abi::Error::InvalidStatus(abi::ReservationStatus::Blocked)
+        );
+        assert_eq!(manager.get(rsvp.id.clone()).await.unwrap(), rsvp);
+        let cancelled = manager
+            .transition(rsvp.id, abi::ReservationStatus::Cancelled)
+            .await
+            .unwrap();
+        assert_eq!(cancelled.status, abi::ReservationStatus::Cancelled as i32);
+        assert!(cancelled.cancellation.is_some());
+
+        // cancelled reservation stays cancelled, together with its cancellation record.
+        let res = manager
+            .transition(cancelled.id.clone(), abi::ReservationStatus::Confirmed)
+            .await;
+        assert_eq!(
+            res.unwrap_err(),
+            abi::Error::InvalidTransition {
+                from: abi::ReservationStatus::Cancelled,
+                to: abi::ReservationStatus::Confirmed,
+            }
+        );
+        assert_eq!(manager.get(cancelled.id.clone()).await.unwrap(), cancelled);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn transition_unknown_rsvp_should_return_not_found() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let res = manager
+            .transition(
+                "2c4ae3e0-27d9-4d11-a0a4-6a2d3e5c3a10".to_string(),
+                abi::ReservationStatus::Confirmed,
+            )
+            .await;
+        assert_eq!(res.unwrap_err(), abi::Error::NotFound);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn update_note_should_work() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        assert!(!rsvp.id.is_empty());
+
+        let new_note = "Mamba out!".to_string();
+        let updated_rsvp = manager
+            .update_note(rsvp.id.clone(), new_note.clone())
+            .await
+            .unwrap();
+        assert_eq!(updated_rsvp.note, new_note);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn reschedule_should_move_rsvp() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        let request = abi::RescheduleRequest {
+            id: rsvp.id.clone(),
+            start: Some("2025-06-05T00:00:00Z".parse().unwrap()),
+            end: Some("2025-06-06T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+
+        let moved = manager.reschedule(request.clone()).await.unwrap();
+        assert_eq!(moved.start, request.start);
+        assert_eq!(moved.end, request.end);
+        assert_eq!(moved.resource_id, rsvp.resource_id);
+        assert_eq!(manager.get(rsvp.id.clone()).await.unwrap(), moved);
+
+        // the old window is released.
+        make_basic_reservation(&manager).await.unwrap();
+
+        let moved = manager
+            .reschedule(abi::RescheduleRequest {
+                resource_id: "room-1".to_string(),
+                ..request
+            })
+            .await
+            .unwrap();
+        assert_eq!(moved.resource_id, "room-1");
+        assert_eq!(moved.note, rsvp.note);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn reschedule_conflict_should_reject() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp1 = make_basic_reservation(&manager).await.unwrap();
+        let rsvp2 = make_reservation(
+            &manager,
+            "gigi",
+            "room-114514",
+            "2025-06-05T00:00:00Z".parse().unwrap(),
+            "2025-06-06T00:00:00Z".parse().unwrap(),
+            "",
+        )
+        .await
+        .unwrap();
+
+        let err = manager
+            .reschedule(abi::RescheduleRequest {
+                id: rsvp2.id.clone(),
+                start: Some("2025-06-03T00:00:00Z".parse().unwrap()),
+                end: Some("2025-06-05T00:00:00Z".parse().unwrap()),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        let info = ReservationConflictInfo::Parsed(ReservationConflict {
+            new: ReservationWindow {
+                rid: "room-114514".to_string(),
+                start: "2025-06-03 00:00:00 UTC".parse().unwrap(),
+                end: "2025-06-05 00:00:00 UTC".parse().unwrap(),
+            },
+            old: ReservationWindow {
+                rid: "room-114514".to_string(),
+                start: "2025-06-01 19:00:00 UTC".parse().unwrap(),
+                end: "2025-06-03 19:00:00 UTC".parse().unwrap(),
+            },
+            buffered: false,
+        });
+        assert_eq!(err, abi::Error::ConflictReservation(info));
+        // nothing is moved.
+        assert_eq!(manager.get(rsvp2.id.clone()).await.unwrap(), rsvp2);
+
+        manager
+            .cancel(rsvp1.id.clone(), "kobe".to_string(), "".to_string())
+            .await
+            .unwrap();
+        let err = manager
+            .reschedule(abi::RescheduleRequest {
+                id: rsvp1.id,
+                start: Some("2025-06-07T00:00:00Z".parse().unwrap()),
+                end: Some("2025-06-08T00:00:00Z".parse().unwrap()),
+                ..Default::default()
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::FinalStatus(abi::ReservationStatus::Cancelled)
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn delete_should_work() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        assert!(!rsvp.id.is_empty());
+
+        // Delete the reservation.
+        manager.delete(rsvp.id.clone()).await.unwrap();
+
+        // Try to get the deleted reservation, should return NotFound error.
+        let res = manager.get(rsvp.id.clone()).await;
+        assert!(res.is_err());
+        assert_eq!(res.unwrap_err(), abi::Error::NotFound);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn delete_unknown_rsvp_should_return_not_found() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let res = manager
+            .delete("2c4ae3e0-27d9-4d11-a0a4-6a2d3e5c3a10".to_string())
+            .await;
+        assert_eq!(res.unwrap_err(), abi::Error::NotFound);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn cancel_should_keep_rsvp_and_record_cancellation() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+
+        let cancelled = manager
+            .cancel(
+                rsvp.id.clone(),
+                "support".to_string(),
+                "double booked".to_string(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(cancelled.id, rsvp.id);
+        assert_eq!(cancelled.status, abi::ReservationStatus::Cancelled as i32);
+        let cancellation = cancelled.cancellation.clone().unwrap();
+        assert_eq!(cancellation.cancelled_by, "support");
+        assert_eq!(cancellation.reason, "double booked");
+        assert!(cancellation.cancelled_at.is_some());
+
+        // cancelled reservation is still there, and cancelling it again changes nothing.
+        assert_eq!(manager.get(rsvp.id.clone()).await.unwrap(), cancelled);
+        let again = manager
+            .cancel(rsvp.id, "kobe".to_string(), "".to_string())
+            .await
+            .unwrap();
+        assert_eq!(again, cancelled);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn cancel_unknown_rsvp_should_return_not_found() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let res = manager
+            .cancel(
+                "2c4ae3e0-27d9-4d11-a0a4-6a2d3e5c3a10".to_string(),
+                "support".to_string(),
+                "".to_string(),
+            )
+            .await;
+        assert_eq!(res.unwrap_err(), abi::Error::NotFound);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn cancelled_rsvp_should_not_block_resource() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        manager
+            .cancel(rsvp.id, "kobe".to_string(), "".to_string())
+            .await
+            .unwrap();
+
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        assert!(!rsvp.id.is_empty());
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn deleted_rsvp_should_be_hidden_and_not_block_resource() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        manager.delete(rsvp.id.clone()).await.unwrap();
+
+        // deleting it again is like deleting an unknown reservation.
+        let res = manager.delete(rsvp.id.clone()).await;
+        assert_eq!(res.unwrap_err(), abi::Error::NotFound);
+        let res = manager.update_note(rsvp.id, "hi.".to_string()).await;
+        assert_eq!(res.unwrap_err(), abi::Error::NotFound);
+
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        assert!(!rsvp.id.is_empty());
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn archive_should_move_old_rsvps() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let old = make_basic_reservation(&manager).await.unwrap();
+        let future = make_reservation(
+            &manager,
+            "kobe",
+            "room-114514",
+            "2099-06-01T12:00:00-07:00".parse().unwrap(),
+            "2099-06-03T12:00:00-07:00".parse().unwrap(),
+            "see you later.",
+        )
+        .await
+        .unwrap();
+        let cancelled = make_reservation(
+            &manager,
+            "kobe",
+            "room-1919810",
+            "2099-06-01T12:00:00-07:00".parse().unwrap(),
+            "2099-06-03T12:00:00-07:00".parse().unwrap(),
+            "never mind.",
+        )
+        .await
+        .unwrap();
+        manager
+            .cancel(cancelled.id.clone(), "kobe".to_string(), "".to_string())
+            .await
+            .unwrap();
+
+        // only the reservation which ended long ago is archived.
+        let archived = manager.archive(Duration::days(30)).await.unwrap();
+        assert_eq!(archived, 1);
+        assert_eq!(
+            manager.get(old.id.clone()).await.unwrap_err(),
+            abi::Error::NotFound
+        );
+        assert_eq!(manager.get(future.id.clone()).await.unwrap(), future);
+
+        // cancelled reservation is archived once the cancellation is old enough.
+        let archived = manager.archive(Duration::zero()).await.unwrap();
+        assert_eq!(archived, 1);
+
+        let query = ReservationQueryBuilder::default()
+            .user_id("kobe")
+            .start("2025-01-01T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+            .end("2100-01-01T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        let rsvps = manager.query_archive(query).await.unwrap();
+        assert_eq!(rsvps.len(), 2);
+        assert_eq!(rsvps[0], old);
+        assert_eq!(rsvps[1].id, cancelled.id);
+        assert_eq!(rsvps[1].status, abi::ReservationStatus::Cancelled as i32);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn block_overlapping_rsvps_should_fail_by_default() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+
+        let err = manager
+            .block(make_block_request(abi::BlockConflictPolicy::Fail))
+            .await
+            .unwrap_err();
+        assert_eq!(err, abi::Error::BlockConflict(vec![rsvp.id.clone()]));
+        // nothing is changed.
+        assert_eq!(manager.get(rsvp.id.clone()).await.unwrap(), rsvp);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn block_should_cancel_overlapping_rsvps() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+
+        let res = manager
+            .block(make_block_request(abi::BlockConflictPolicy::Cancel))
+            .await
+            .unwrap();
+        let block = res.block.unwrap();
+        assert_eq!(block.user_id, abi::SYSTEM_USER_ID);
+        assert_eq!(block.status, abi::ReservationStatus::Blocked as i32);
+        assert_eq!(res.cancelled.len(), 1);
+        let cancelled = &res.cancelled[0];
+        assert_eq!(cancelled.id, rsvp.id);
+        assert_eq!(cancelled.status, abi::ReservationStatus::Cancelled as i32);
+        let cancellation = cancelled.cancellation.as_ref().unwrap();
+        assert_eq!(cancellation.cancelled_by, abi::SYSTEM_USER_ID);
+        assert_eq!(cancellation.reason, "maintenance");
+
+        // the block is queried like any reservation.
+        let query = ReservationQueryBuilder::default()
+            .resource_id("room-114514")
+            .start("2025-06-01T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+            .end("2025-06-04T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+            .status(abi::ReservationStatus::Blocked as i32)
+            .build()
+            .unwrap();
+        assert_eq!(manager.query(query).await.unwrap().items, vec![block]);
+
+        // and keeps users away from the resource.
+        let err = make_basic_reservation(&manager).await.unwrap_err();
+        assert!(matches!(err, abi::Error::ConflictReservation(_)));
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn block_should_find_rsvps_overlapping_through_buffers() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        manager
+            .set_buffers(abi::SetBuffersRequest {
+                resource_id: "room-114514".to_string(),
+                after: Some(abi::Duration {
+                    seconds: 3600,
+                    nanos: 0,
+                }),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        let request = |policy: abi::BlockConflictPolicy| abi::BlockRequest {
+            start: Some("2025-06-03T19:30:00Z".parse().unwrap()),
+            end: Some("2025-06-03T20:00:00Z".parse().unwrap()),
+            ..make_block_request(policy)
+        };
+
+        let err = manager
+            .block(request(abi::BlockConflictPolicy::Fail))
+            .await
+            .unwrap_err();
+        assert_eq!(err, abi::Error::BlockConflict(vec![rsvp.id.clone()]));
+
+        let res = manager
+            .block(request(abi::BlockConflictPolicy::Cancel))
+            .await
+            .unwrap();
+        assert!(res.block.is_some());
+        assert_eq!(res.cancelled.len(), 1);
+        assert_eq!(res.cancelled[0].id, rsvp.id);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn reserve_as_system_should_reject() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let err = make_reservation(
+            &manager,
+            abi::SYSTEM_USER_ID,
+            "room-114514",
+            "2025-06-01T12:00:00-07:00".parse().unwrap(),
+            "2025-06-03T12:00:00-07:00".parse().unwrap(),
+            "",
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::InvalidUserId(abi::SYSTEM_USER_ID.to_string())
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn reserve_with_status_other_than_pending_should_reject() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = abi::Reservation {
+            status: abi::ReservationStatus::Blocked as i32,
+            ..abi::Reservation::new_pending(
+                "kobe",
+                "room-114514",
+                "2025-06-01T12:00:00-07:00".parse().unwrap(),
+                "2025-06-03T12:00:00-07:00".parse().unwrap(),
+                "",
+            )
+        };
+        let err = manager.reserve(rsvp.clone()).await.unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::InvalidStatus(abi::ReservationStatus::Blocked)
+        );
+
+        let confirmed = abi::Reservation {
+            status: abi::ReservationStatus::Confirmed as i32,
+            ..rsvp
+        };
+        let report = manager
+            .import(vec![confirmed], abi::ImportOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(
+            report.rows,
+            [abi::ImportOutcome::Invalid(abi::Error::InvalidStatus(
+                abi::ReservationStatus::Confirmed
+            ))]
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn expired_hold_should_not_block_resource() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_hold(&manager, "room-114514", "2025-06-01T00:00:00Z").await;
+
+        // the reaper has not run, the hold is released by the new reservation.
+        let rsvp2 = make_basic_reservation(&manager).await.unwrap();
+        assert!(!rsvp2.id.is_empty());
+        let expired = manager.get(rsvp.id).await.unwrap();
+        assert_eq!(expired.status, abi::ReservationStatus::Cancelled as i32);
+        let cancellation = expired.cancellation.unwrap();
+        assert_eq!(cancellation.cancelled_by, abi::SYSTEM_USER_ID);
+        assert_eq!(cancellation.reason, "hold expired");
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn moved_rsvp_should_release_expired_holds() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let hold = make_hold(&manager, "room-1", "2025-06-01T00:00:00Z").await;
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+
+        // any update moving a reservation releases the holds in its way, not only reschedule.
+        sqlx::query("UPDATE rsvp.reservations SET resource_id = 'room-1' WHERE id = $1::uuid")
+            .bind(Uuid::parse_str(&rsvp.id).unwrap())
+            .execute(&migrated_pool)
+            .await
+            .unwrap();
+        let expired = manager.get(hold.id).await.unwrap();
+        assert_eq!(expired.status, abi::ReservationStatus::Cancelled as i32);
+        assert_eq!(
+            expired.cancellation.unwrap().cancelled_by,
+            abi::SYSTEM_USER_ID
+        );
+
+        let system_user_id: String = sqlx::query("SELECT rsvp.system_user_id()")
+            .fetch_one(&migrated_pool)
+            .await
+            .unwrap()
+            .get(0);
+        assert_eq!(system_user_id, abi::SYSTEM_USER_ID);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn expire_holds_should_cancel_stale_holds() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let expired = make_hold(&manager, "room-1", "2025-06-01T00:00:00Z").await;
+        let held = make_hold(&manager, "room-2", "2100-01-01T00:00:00Z").await;
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+
+        assert_eq!(manager.expire_holds().await.unwrap(), 1);
+        assert_eq!(manager.expire_holds().await.unwrap(), 0);
+        let expired = manager.get(expired.id).await.unwrap();
+        assert_eq!(expired.status, abi::ReservationStatus::Cancelled as i32);
+        assert_eq!(manager.get(held.id.clone()).await.unwrap(), held);
+        assert_eq!(manager.get(rsvp.id.clone()).await.unwrap(), rsvp);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn confirm_expired_hold_should_reject() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_hold(&manager, "room-114514", "2025-06-01T00:00:00Z").await;
+
+        let err = manager.change_status(rsvp.id.clone()).await.unwrap_err();
+        assert_eq!(
+            err,
+            abi::Error::InvalidTransition {
+                from: abi::ReservationStatus::Cancelled,
+                to: abi::ReservationStatus::Confirmed,
+            }
+        );
+        let rsvp = manager.get(rsvp.id).await.unwrap();
+        assert_eq!(rsvp.status, abi::ReservationStatus::Cancelled as i32);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn buffers_should_keep_resource_free_around_reservations() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let at = |s: &str| s.parse::<DateTime<FixedOffset>>().unwrap();
+        let rsvp = |start: &str, end: &str| {
+            make_reservation(&manager, "kobe", "room-114514", at(start), at(end), "")
+        };
+        let set_buffers = |after_minutes: i64| {
+            manager.set_buffers(abi::SetBuffersRequest {
+                resource_id: "room-114514".to_string(),
+                before: None,
+                after: Some(abi::Duration {
+                    seconds: after_minutes * 60,
+                    nanos: 0,
+                }),
+            })
+        };
+        let window = |start: &str, end: &str| ReservationWindow {
+            rid: "room-114514".to_string(),
+            start: start.parse().unwrap(),
+            end: end.parse().unwrap(),
+        };
+        set_buffers(15).await.unwrap();
+        let first = rsvp("2099-06-01T10:00:00Z", "2099-06-01T11:00:00Z")
+            .await
+            .unwrap();
+        assert_eq!(first.end, Some("2099-06-01T11:00:00Z".parse().unwrap()));
+
+        let err = rsvp("2099-06-01T11:00:00Z", "2099-06-01T12:00:00Z")
+            .await
+            .unwrap_err();
+        let info = ReservationConflictInfo::Parsed(ReservationConflict {
+            new: window("2099-06-01 11:00:00 UTC", "2099-06-01 12:15:00 UTC"),
+            old: window("2099-06-01 10:00:00 UTC", "2099-06-01 11:15:00 UTC"),
+            buffered: true,
+        });
+        assert_eq!(err, abi::Error::ConflictReservation(info));
+
+        // literal overlaps are still reported without the buffers.
+        let err = rsvp("2099-06-01T10:30:00Z", "2099-06-01T11:30:00Z")
+            .await
+            .unwrap_err();
+        let abi::Error::ConflictReservation(ReservationConflictInfo::Parsed(conflict)) = err else {
+            panic!("expected parsed conflict, got {err:?}");
+        };
+        assert!(!conflict.buffered);
+        assert_eq!(
+            conflict.old.end,
+            "2099-06-01 11:00:00 UTC".parse::<DateTime<Utc>>().unwrap()
+        );
+
+        rsvp("2099-06-01T11:15:00Z", "2099-06-01T12:00:00Z")
+            .await
+            .unwrap();
+        let slots = manager
+            .availability(abi::AvailabilityRequest {
+                resource_id: "room-114514".to_string(),
+                start: Some("2099-06-01T09:00:00Z".parse().unwrap()),
+                end: Some("2099-06-01T13:00:00Z".parse().unwrap()),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let slot = |start: &str, end: &str| {
+            abi::TimeSlot::new(start.parse().unwrap(), end.parse().unwrap())
+        };
+        assert_eq!(
+            slots,
+            [
+                slot("2099-06-01T09:00:00Z", "2099-06-01T09:45:00Z"),
+                slot("2099-06-01T12:15:00Z", "2099-06-01T13:00:00Z"),
+            ]
+        );
+
+        // longer buffers would make the reservations conflict.
+        let err = set_buffers(60).await.unwrap_err();
+        assert!(matches!(
+            err,
+            abi::Error::ConflictReservation(ReservationConflictInfo::Parsed(ReservationConflict {
+                buffered: true,
+                ..
+            }))
+        ));
+        set_buffers(0).await.unwrap();
+        rsvp("2099-06-01T11:00:00Z", "2099-06-01T11:15:00Z")
+            .await
+            .unwrap();
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn set_buffers_should_wait_for_reservations_being_made() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let at = |s: &str| s.parse::<DateTime<FixedOffset>>().unwrap();
+        let rsvp = make_reservation(
+            &manager,
+            "kobe",
+            "room-114514",
+            at("2099-06-01T11:30:00Z"),
+            at("2099-06-01T12:00:00Z"),
+            "",
+        )
+        .await
+        .unwrap();
+
+        // a reservation made with no buffers yet, not committed.
+        let mut tx = migrated_pool.begin().await.unwrap();
+        sqlx::query("INSERT INTO rsvp.reservations (user_id, resource_id, timespan, note) VALUES ('gigi', 'room-114514', '[2099-06-01 10:00Z, 2099-06-01 11:00Z)', '')")
+            .execute(&mut tx)
+            .await
+            .unwrap();
+        let set_buffers = tokio::spawn({
+            let manager = manager.clone();
+            async move {
+                manager
+                    .set_buffers(abi::SetBuffersRequest {
+                        resource_id: "room-114514".to_string(),
+                        before: None,
+                        after: Some(abi::Duration {
+                            seconds: 3600,
+                            nanos: 0,
+                        }),
+                    })
+                    .await
+            }
+        });
+        tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        assert!(!set_buffers.is_finished());
+        tx.commit().await.unwrap();
+
+        // the buffers apply to the new reservation too, which then conflicts with the other.
+        let err = set_buffers.await.unwrap().unwrap_err();
+        assert!(matches!(
+            err,
+            abi::Error::ConflictReservation(ReservationConflictInfo::Parsed(ReservationConflict {
+                buffered: true,
+                ..
+            }))
+        ));
+        assert_eq!(manager.get(rsvp.id.clone()).await.unwrap(), rsvp);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn set_buffers_should_keep_update_time() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_reservation(
+            &manager,
+            "kobe",
+            "room-114514",
+            "2099-06-01T10:00:00Z".parse().unwrap(),
+            "2099-06-01T11:00:00Z".parse().unwrap(),
+            "",
+        )
+        .await
+        .unwrap();
+        manager
+            .set_buffers(abi::SetBuffersRequest {
+                resource_id: "room-114514".to_string(),
+                before: None,
+                after: Some(abi::Duration {
+                    seconds: 900,
+                    nanos: 0,
+                }),
+            })
+            .await
+            .unwrap();
+
+        // the reservation is widened by the buffers, but is not changed itself.
+        let effective: PgRange<DateTime<Utc>> =
+            sqlx::query("SELECT effective_timespan FROM rsvp.reservations WHERE id = $1::uuid")
+                .bind(Uuid::parse_str(&rsvp.id).unwrap())
+                .fetch_one(&migrated_pool)
+                .await
+                .unwrap()
+                .get(0);
+        assert_eq!(
+            effective.end,
+            std::ops::Bound::Excluded("2099-06-01T11:15:00Z".parse().unwrap())
+        );
+        assert_eq!(manager.get(rsvp.id.clone()).await.unwrap(), rsvp);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn expired_hold_should_not_block_resource_through_buffer() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let at = |s: &str| s.parse::<DateTime<FixedOffset>>().unwrap();
+        let mut holds = Vec::new();
+        for rid in ["room-1", "room-2"] {
+            manager
+                .set_buffers(abi::SetBuffersRequest {
+                    resource_id: rid.to_string(),
+                    after: Some(abi::Duration {
+                        seconds: 15 * 60,
+                        nanos: 0,
+                    }),
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            let hold = abi::Reservation::new_pending(
+                "kobe",
+                rid,
+                at("2099-06-01T09:00:00Z"),
+                at("2099-06-01T10:00:00Z"),
+                "pay later",
+            )
+            .with_hold_expires_at("2025-06-01T00:00:00Z".parse().unwrap());
+            holds.push(manager.reserve(hold).await.unwrap());
+        }
+
+        // the hold only overlaps through its buffer, it is released by the new reservation.
+        make_reservation(
+            &manager,
+            "gigi",
+            "room-1",
+            at("2099-06-01T10:05:00Z"),
+            at("2099-06-01T11:00:00Z"),
+            "",
+        )
+        .await
+        .unwrap();
+        let expired = manager.get(holds[0].id.clone()).await.unwrap();
+        assert_eq!(expired.status, abi::ReservationStatus::Cancelled as i32);
+
+        // the slot availability reports free could be reserved.
+        let res = manager
+            .find_slot(abi::FindSlotRequest {
+                resource_ids: vec!["room-2".to_string()],
+                start: Some("2099-06-01T10:00:00Z".parse().unwrap()),
+                end: Some("2099-06-01T12:00:00Z".parse().unwrap()),
+                duration: Some(abi::Duration {
+                    seconds: 3600,
+                    nanos: 0,
+                }),
+                reservation: Some(abi::Reservation::new_pending(
+                    "gigi",
+                    "",
+                    at("2099-06-01T10:00:00Z"),
+                    at("2099-06-01T11:00:00Z"),
+                    "",
+                )),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+        let reserved = res.reservation.unwrap();
+        assert_eq!(
+            reserved.start,
+            Some("2099-06-01T10:00:00Z".parse().unwrap())
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn availability_should_return_free_slots() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let at = |s: &str| s.parse::<DateTime<FixedOffset>>().unwrap();
+        let rsvp = |start: &str, end: &str| {
+            make_reservation(&manager, "kobe", "room-114514", at(start), at(end), "")
+        };
+        rsvp("2025-06-01T10:07:00Z", "2025-06-01T11:00:00Z")
+            .await
+            .unwrap();
+        let cancelled = rsvp("2025-06-01T11:30:00Z", "2025-06-01T12:00:00Z")
+            .await
+            .unwrap();
+        manager
+            .cancel(cancelled.id, "kobe".to_string(), "".to_string())
+            .await
+            .unwrap();
+        rsvp("2025-06-01T13:00:00Z", "2025-06-01T14:10:00Z")
+            .await
+            .unwrap();
+        // reservations of other resources and expired holds do not take the resource.
+        make_reservation(
+            &manager,
+            "kobe",
+            "room-1",
+            at("2025-06-01T09:00:00Z"),
+            at("2025-06-01T18:00:00Z"),
+            "",
+        )
+        .await
+        .unwrap();
+        let hold = abi::Reservation::new_pending(
+            "kobe",
+            "room-114514",
+            at("2025-06-01T16:00:00Z"),
+            at("2025-06-01T17:00:00Z"),
+            "",
+        )
+        .with_hold_expires_at(at("2025-06-01T00:00:00Z"));
+        manager.reserve(hold).await.unwrap();
+
+        let slot = |start: &str, end: &str| {
+            abi::TimeSlot::new(at(start).with_timezone(&Utc), at(end).with_timezone(&Utc))
+        };
+        let mut request = abi::AvailabilityRequest {
+            resource_id: "room-114514".to_string(),
+            start: Some("2025-06-01T09:00:00Z".parse().unwrap()),
+            end: Some("2025-06-01T18:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        let slots = manager.availability(request.clone()).await.unwrap();
+        assert_eq!(
+            slots,
+            vec![
+                slot("2025-06-01T09:00:00Z", "2025-06-01T10:07:00Z"),
+                slot("2025-06-01T11:00:00Z", "2025-06-01T13:00:00Z"),
+                slot("2025-06-01T14:10:00Z", "2025-06-01T18:00:00Z"),
+            ]
+        );
+
+        // slots are aligned to 15 minutes, then the short ones are dropped.
+        request.granularity = Some(abi::Duration {
+            seconds: 15 * 60,
+            nanos: 0,
+        });
+        request.min_duration = Some(abi::Duration {
+            seconds: 2 * 3600,
+            nanos: 0,
+        });
+        let slots = manager.availability(request).await.unwrap();
+        assert_eq!(
+            slots,
+            vec![
+                slot("2025-06-01T11:00:00Z", "2025-06-01T13:00:00Z"),
+                slot("2025-06-01T14:15:00Z", "2025-06-01T18:00:00Z"),
+            ]
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn availability_of_busy_resource_should_be_empty() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        make_basic_reservation(&manager).await.unwrap();
+
+        let request = abi::AvailabilityRequest {
+            resource_id: "room-114514".to_string(),
+            start: Some("2025-06-02T00:00:00Z".parse().unwrap()),
+            end: Some("2025-06-03T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+        assert!(manager.availability(request).await.unwrap().is_empty());
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn find_slot_should_return_earliest_free_candidate() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let at = |s: &str| s.parse::<DateTime<FixedOffset>>().unwrap();
+        for (rid, end) in [
+            ("room-1", "2025-06-01T12:00:00Z"),
+            ("room-2", "2025-06-01T10:30:00Z"),
+            ("room-3", "2025-06-01T10:30:00Z"),
+        ] {
+            make_reservation(
+                &manager,
+                "kobe",
+                rid,
+                at("2025-06-01T09:00:00Z"),
+                at(end),
+                "",
+            )
+            .await
+            .unwrap();
+        }
+
+        let mut request = abi::FindSlotRequest {
+            resource_ids: vec!["room-1".into(), "room-3".into(), "room-2".into()],
+            start: Some("2025-06-01T09:00:00Z".parse().unwrap()),
+            end: Some("2025-06-01T18:00:00Z".parse().unwrap()),
+            duration: Some(abi::Duration {
+                seconds: 3600,
+                nanos: 0,
+            }),
+            ..Default::default()
+        };
+        // room-2 and room-3 are free at the same time, the earlier candidate wins.
+        let res = manager.find_slot(request.clone()).await.unwrap();
+        assert_eq!(res.resource_id, "room-3");
+        assert_eq!(
+            res.slot,
+            Some(abi::TimeSlot::new(
+                at("2025-06-01T10:30:00Z").with_timezone(&Utc),
+                at("2025-06-01T11:30:00Z").with_timezone(&Utc),
+            ))
+        );
+        assert_eq!(res.reservation, None);
+
+        request.granularity = Some(abi::Duration {
+            seconds: 3600,
+            nanos: 0,
+        });
+        let res = manager.find_slot(request.clone()).await.unwrap();
+        assert_eq!(
+            res.slot.unwrap().start,
+            Some("2025-06-01T11:00:00Z".parse().unwrap())
+        );
+
+        // no candidate is free for 9 hours.
+        request.duration = Some(abi::Duration {
+            seconds: 9 * 3600,
+            nanos: 0,
+        });
+        request.reservation = Some(abi::Reservation::new_pending(
+            "kobe",
+            "",
+            at("2025-06-01T00:00:00Z"),
+            at("2025-06-01T00:00:00Z"),
+            "",
+        ));
+        let res = manager.find_slot(request).await.unwrap();
+        assert_eq!(res, abi::FindSlotResponse::default());
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn concurrent_find_slot_should_reserve_different_slots() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let at = |s: &str| s.parse::<DateTime<FixedOffset>>().unwrap();
+        let request = |uid: &str| abi::FindSlotRequest {
+            resource_ids: vec!["room-114514".into()],
+            start: Some("2025-06-01T09:00:00Z".parse().unwrap()),
+            end: Some("2025-06-01T12:00:00Z".parse().unwrap()),
+            duration: Some(abi::Duration {
+                seconds: 3600,
+                nanos: 0,
+            }),
+            reservation: Some(abi::Reservation::new_pending(
+                uid,
+                "",
+                at("2025-06-01T00:00:00Z"),
+                at("2025-06-01T00:00:00Z"),
+                "team sync",
+            )),
+            ..Default::default()
+        };
+
+        let (r1, r2) = tokio::join!(
+            manager.find_slot(request("kobe")),
+            manager.find_slot(request("gigi"))
+        );
+        let mut rsvps = [
+            r1.unwrap().reservation.unwrap(),
+            r2.unwrap().reservation.unwrap(),
+        ];
+        rsvps.sort_by_key(|rsvp| rsvp.start.unwrap().seconds);
+        assert_eq!(
+            rsvps[0].start,
+            Some("2025-06-01T09:00:00Z".parse().unwrap())
+        );
+        assert_eq!(
+            rsvps[1].start,
+            Some("2025-06-01T10:00:00Z".parse().unwrap())
+        );
+        assert_eq!(rsvps[0].resource_id, "room-114514");
+        assert_eq!(rsvps[0].note, "team sync");
+        assert_ne!(rsvps[0].user_id, rsvps[1].user_id);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn get_should_work() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+        assert!(!rsvp.id.is_empty());
+
+        // Query reservations by room ID.
+        let get = manager.get(rsvp.id.clone()).await.unwrap();
+        assert_eq!(rsvp, get);
+    }
 
     #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
     async fn query_should_work() {
@@ -257,7 +2552,7 @@ mod tests {
             .status(abi::ReservationStatus::Pending as i32)
             .build()
             .unwrap();
-        let rsvps = manager.query(query).await.unwrap();
+        let rsvps = manager.query(query).await.unwrap().items;
         assert_eq!(rsvps.len(), 1);
         assert_eq!(rsvps[0].id, rsvp.id);
 
@@ -278,7 +2573,7 @@ mod tests {
             .status(abi::ReservationStatus::Pending as i32)
             .build()
             .unwrap();
-        let rsvps = manager.query(query).await.unwrap();
+        let rsvps = manager.query(query).await.unwrap().items;
         assert_eq!(rsvps.len(), 0);
 
         // if the state is not correct, should return empty.
@@ -298,16 +2593,436 @@ mod tests {
             .status(abi::ReservationStatus::Confirmed as i32)
             .build()
             .unwrap();
-        let rsvps = manager.query(query.clone()).await.unwrap();
+        let rsvps = manager.query(query.clone()).await.unwrap().items;
         assert_eq!(rsvps.len(), 0);
 
         // change state to confirmed, should return result
         let rsvp = manager.change_status(rsvp.id).await.unwrap();
-        let rsvps = manager.query(query).await.unwrap();
+        let rsvps = manager.query(query).await.unwrap().items;
         assert_eq!(rsvps.len(), 1);
         assert_eq!(rsvp.id, rsvps[0].id);
     }
 
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn query_by_multiple_statuses_should_work() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let confirmed = make_basic_reservation(&manager).await.unwrap();
+        let confirmed = manager.change_status(confirmed.id).await.unwrap();
+        let pending = make_reservation(
+            &manager,
+            "kobe",
+            "room-1",
+            "2025-06-01T12:00:00-07:00".parse().unwrap(),
+            "2025-06-03T12:00:00-07:00".parse().unwrap(),
+            "",
+        )
+        .await
+        .unwrap();
+        let cancelled = make_reservation(
+            &manager,
+            "kobe",
+            "room-2",
+            "2025-06-01T12:00:00-07:00".parse().unwrap(),
+            "2025-06-03T12:00:00-07:00".parse().unwrap(),
+            "",
+        )
+        .await
+        .unwrap();
+        manager
+            .cancel(cancelled.id, "kobe".to_string(), "".to_string())
+            .await
+            .unwrap();
+
+        let builder = || {
+            let mut builder = ReservationQueryBuilder::default();
+            builder
+                .user_id("kobe")
+                .start("2025-06-01T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+                .end("2025-06-04T00:00:00Z".parse::<abi::Timestamp>().unwrap());
+            builder
+        };
+
+        // unknown status returns reservations of any status.
+        let query = builder().build().unwrap();
+        assert_eq!(manager.query(query).await.unwrap().items.len(), 3);
+
+        let query = builder()
+            .statuses(vec![
+                abi::ReservationStatus::Pending as i32,
+                abi::ReservationStatus::Confirmed as i32,
+            ])
+            .build()
+            .unwrap();
+        let mut ids: Vec<_> = manager
+            .query(query)
+            .await
+            .unwrap()
+            .items
+            .into_iter()
+            .map(|rsvp| rsvp.id)
+            .collect();
+        ids.sort();
+        let mut expected = vec![confirmed.id, pending.id];
+        expected.sort();
+        assert_eq!(ids, expected);
+
+        // a status which does not exist is an error rather than no filter at all.
+        let query = builder().statuses(vec![42]).build().unwrap();
+        assert_eq!(
+            manager.query(query).await.unwrap_err(),
+            abi::Error::InvalidStatus(abi::ReservationStatus::Unknown)
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn query_by_match_mode_should_work() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        // 2025-06-01T19:00:00Z - 2025-06-03T19:00:00Z
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+
+        let query = |start: &str, end: &str, mode: abi::MatchMode| {
+            ReservationQueryBuilder::default()
+                .resource_id("room-114514")
+                .start(start.parse::<abi::Timestamp>().unwrap())
+                .end(end.parse::<abi::Timestamp>().unwrap())
+                .match_mode(mode as i32)
+                .build()
+                .unwrap()
+        };
+        let cases = [
+            (
+                "2025-06-02T00:00:00Z",
+                "2025-06-04T00:00:00Z",
+                abi::MatchMode::Overlaps,
+                true,
+            ),
+            (
+                "2025-06-02T00:00:00Z",
+                "2025-06-04T00:00:00Z",
+                abi::MatchMode::ContainedBy,
+                false,
+            ),
+            (
+                "2025-06-01T00:00:00Z",
+                "2025-06-04T00:00:00Z",
+                abi::MatchMode::ContainedBy,
+                true,
+            ),
+            (
+                "2025-06-02T00:00:00Z",
+                "2025-06-03T00:00:00Z",
+                abi::MatchMode::Contains,
+                true,
+            ),
+            (
+                "2025-06-01T00:00:00Z",
+                "2025-06-03T00:00:00Z",
+                abi::MatchMode::Contains,
+                false,
+            ),
+            (
+                "2025-06-01T00:00:00Z",
+                "2025-06-02T00:00:00Z",
+                abi::MatchMode::StartsWithin,
+                true,
+            ),
+            (
+                "2025-06-02T00:00:00Z",
+                "2025-06-04T00:00:00Z",
+                abi::MatchMode::StartsWithin,
+                false,
+            ),
+            (
+                "2025-06-04T00:00:00Z",
+                "2025-06-05T00:00:00Z",
+                abi::MatchMode::Overlaps,
+                false,
+            ),
+        ];
+        for (start, end, mode, found) in cases {
+            let rsvps = manager.query(query(start, end, mode)).await.unwrap().items;
+            let expected = if found { vec![rsvp.clone()] } else { vec![] };
+            assert_eq!(rsvps, expected, "{mode:?} {start} - {end}");
+        }
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn query_open_ended_range_should_work() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        // 2025-06-01T19:00:00Z - 2025-06-03T19:00:00Z
+        let rsvp = make_basic_reservation(&manager).await.unwrap();
+
+        // everything from a date on.
+        let query = ReservationQueryBuilder::default()
+            .user_id("kobe")
+            .start("2025-06-03T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        assert_eq!(
+            manager.query(query).await.unwrap().items,
+            vec![rsvp.clone()]
+        );
+
+        // everything before a date.
+        let query = ReservationQueryBuilder::default()
+            .user_id("kobe")
+            .end("2025-06-01T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+            .build()
+            .unwrap();
+        assert!(manager.query(query).await.unwrap().items.is_empty());
+
+        // no range at all.
+        let query = ReservationQueryBuilder::default()
+            .user_id("kobe")
+            .match_mode(abi::MatchMode::ContainedBy as i32)
+            .build()
+            .unwrap();
+        assert_eq!(manager.query(query).await.unwrap().items, vec![rsvp]);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn query_with_cursor_should_walk_every_rsvp_once() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        // reservations with the same start are ordered by id.
+        let mut expected = Vec::new();
+        for i in 0..25 {
+            let rsvp = make_reservation(
+                &manager,
+                "kobe",
+                &format!("room-{}", i % 5),
+                format!("2025-06-0{}T12:00:00-07:00", 1 + i / 5)
+                    .parse()
+                    .unwrap(),
+                format!("2025-06-0{}T13:00:00-07:00", 1 + i / 5)
+                    .parse()
+                    .unwrap(),
+                "",
+            )
+            .await
+            .unwrap();
+            expected.push(rsvp);
+        }
+        expected.sort_by_key(|rsvp| (rsvp.start.unwrap().seconds, rsvp.id.clone()));
+
+        let manager = &manager;
+        let walk = |desc: bool| async move {
+            let mut rsvps = Vec::new();
+            let mut cursor = String::new();
+            loop {
+                let query = ReservationQueryBuilder::default()
+                    .user_id("kobe")
+                    .page_size(10)
+                    .desc(desc)
+                    .cursor(cursor)
+                    .build()
+                    .unwrap();
+                let page = manager.query(query).await.unwrap().items;
+                let Some(last) = page.last() else {
+                    return rsvps;
+                };
+                cursor = abi::QueryCursor::after(last, abi::SortBy::Start)
+                    .unwrap()
+                    .encode();
+                // reservations made behind the cursor do not shift the following pages.
+                if rsvps.is_empty() && !desc {
+                    make_reservation(
+                        manager,
+                        "kobe",
+                        "room-5",
+                        "2025-05-31T12:00:00-07:00".parse().unwrap(),
+                        "2025-05-31T13:00:00-07:00".parse().unwrap(),
+                        "",
+                    )
+                    .await
+                    .unwrap();
+                }
+                rsvps.extend(page);
+            }
+        };
+
+        let rsvps = walk(false).await;
+        assert_eq!(rsvps, expected);
+        let rsvps = walk(true).await;
+        assert_eq!(rsvps.len(), expected.len() + 1);
+        expected.reverse();
+        assert_eq!(rsvps[..expected.len()], expected);
+
+        let query = ReservationQueryBuilder::default()
+            .cursor("bad")
+            .build()
+            .unwrap();
+        assert_eq!(
+            manager.query(query).await.unwrap_err(),
+            abi::Error::InvalidCursor("bad".to_string())
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn query_should_report_paging_info() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        for i in 0..15 {
+            make_reservation(
+                &manager,
+                "kobe",
+                &format!("room-{i}"),
+                "2025-06-01T12:00:00-07:00".parse().unwrap(),
+                "2025-06-03T12:00:00-07:00".parse().unwrap(),
+                "",
+            )
+            .await
+            .unwrap();
+        }
+
+        let query = ReservationQueryBuilder::default()
+            .user_id("kobe")
+            .with_total_count(true)
+            .build()
+            .unwrap();
+        let first = manager.query(query.clone()).await.unwrap();
+        assert_eq!(first.items.len(), 10);
+        assert_eq!(first.total_count, Some(15));
+        assert!(first.has_more);
+        assert_eq!(first.next_page, Some(2));
+        assert!(first.next_cursor.is_some());
+
+        let query = abi::ReservationQuery {
+            page: 2,
+            with_total_count: false,
+            ..query
+        };
+        let second = manager.query(query.clone()).await.unwrap();
+        assert_eq!(second.items.len(), 5);
+        assert_eq!(second.total_count, None);
+        assert!(!second.has_more);
+        assert_eq!(second.next_page, None);
+        assert_eq!(second.next_cursor, None);
+
+        // continuing from the cursor returns the same page.
+        let query = abi::ReservationQuery {
+            cursor: first.next_cursor.unwrap().encode(),
+            ..query
+        };
+        assert_eq!(manager.query(query).await.unwrap().items, second.items);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn query_should_sort_by_requested_key() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let mut rsvps = Vec::new();
+        for i in 0..12 {
+            // durations repeat, so ties are broken by id.
+            let rsvp = make_reservation(
+                &manager,
+                &format!("user-{}", i % 4),
+                &format!("room-{}", 11 - i),
+                "2025-06-01T12:00:00-07:00".parse().unwrap(),
+                format!("2025-06-01T{}:00:00-07:00", 13 + i % 3)
+                    .parse()
+                    .unwrap(),
+                "",
+            )
+            .await
+            .unwrap();
+            rsvps.push(rsvp);
+        }
+        let walk = |sort_by: abi::SortBy, desc: bool| {
+            let manager = &manager;
+            async move {
+                let mut items = Vec::new();
+                let mut cursor = String::new();
+                loop {
+                    let query = ReservationQueryBuilder::default()
+                        .sort_by(sort_by as i32)
+                        .desc(desc)
+                        .cursor(cursor)
+                        .build()
+                        .unwrap();
+                    let result = manager.query(query).await.unwrap();
+                    items.extend(result.items);
+                    match result.next_cursor {
+                        Some(next) => cursor = next.encode(),
+                        None => return items,
+                    }
+                }
+            }
+        };
+
+        // most recently created first.
+        let mut expected = rsvps.clone();
+        expected.reverse();
+        assert_eq!(walk(abi::SortBy::CreatedAt, true).await, expected);
+
+        let mut expected = rsvps.clone();
+        expected.sort_by_key(|rsvp| rsvp.resource_id.clone());
+        assert_eq!(walk(abi::SortBy::ResourceId, false).await, expected);
+
+        let mut expected = rsvps.clone();
+        expected.sort_by_key(|rsvp| (rsvp.user_id.clone(), rsvp.id.clone()));
+        assert_eq!(walk(abi::SortBy::UserId, false).await, expected);
+
+        let mut expected = rsvps.clone();
+        expected.sort_by_key(|rsvp| (rsvp.end.unwrap().seconds, rsvp.id.clone()));
+        expected.reverse();
+        assert_eq!(walk(abi::SortBy::Duration, true).await, expected);
+
+        // a cursor of another sort is rejected.
+        let cursor = abi::QueryCursor::after(&rsvps[0], abi::SortBy::Duration).unwrap();
+        let query = ReservationQueryBuilder::default()
+            .cursor(cursor.encode())
+            .build()
+            .unwrap();
+        assert_eq!(
+            manager.query(query).await.unwrap_err(),
+            abi::Error::InvalidCursor(cursor.encode())
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn query_stream_should_return_every_rsvp() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let mut expected = Vec::new();
+        // more than the max page size.
+        for i in 0..150 {
+            let rsvp = make_reservation(
+                &manager,
+                "kobe",
+                &format!("room-{i:03}"),
+                "2025-06-01T12:00:00-07:00".parse().unwrap(),
+                "2025-06-03T12:00:00-07:00".parse().unwrap(),
+                "",
+            )
+            .await
+            .unwrap();
+            expected.push(rsvp);
+        }
+
+        let query = ReservationQueryBuilder::default()
+            .user_id("kobe")
+            .sort_by(abi::SortBy::ResourceId as i32)
+            .page_size(10)
+            .build()
+            .unwrap();
+        let stream = manager.query_stream(query.clone()).await.unwrap();
+        let rsvps: Vec<_> = stream.map(|rsvp| rsvp.unwrap()).collect().await;
+        assert_eq!(rsvps, expected);
+
+        // dropping the stream early releases the connection, the only one of this pool.
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .max_connections(1)
+            .connect_with(migrated_pool.connect_options().clone())
+            .await
+            .unwrap();
+        let manager = ReservationManager::new(pool);
+        let mut stream = manager.query_stream(query).await.unwrap();
+        assert_eq!(stream.next().await.unwrap().unwrap(), expected[0]);
+        drop(stream);
+        let get = manager.get(expected[1].id.clone());
+        let rsvp = tokio::time::timeout(std::time::Duration::from_secs(5), get)
+            .await
+            .expect("connection is not released");
+        assert_eq!(rsvp.unwrap(), expected[1]);
+    }
+
     /// Helper functions to create a reservation for testing.
     async fn make_basic_reservation(
         manager: &ReservationManager,
@@ -323,6 +3038,99 @@ mod tests {
         .await
     }
 
+    async fn make_hold(
+        manager: &ReservationManager,
+        rid: &str,
+        expires_at: &str,
+    ) -> abi::Reservation {
+        let rsvp = abi::Reservation::new_pending(
+            "kobe",
+            rid,
+            "2025-06-01T12:00:00-07:00".parse().unwrap(),
+            "2025-06-03T12:00:00-07:00".parse().unwrap(),
+            "pay later",
+        )
+        .with_hold_expires_at(expires_at.parse().unwrap());
+        manager.reserve(rsvp).await.unwrap()
+    }
+
+    /// Rows of an import: created, invalid, conflicting with `existing`, created, conflicting with the first row.
+    fn make_import_rows(existing: &abi::Reservation) -> Vec<abi::Reservation> {
+        let first = abi::Reservation::new_pending(
+            "gigi",
+            "room-1",
+            "2025-06-01T09:00:00Z".parse().unwrap(),
+            "2025-06-01T10:00:00Z".parse().unwrap(),
+            "imported",
+        );
+        vec![
+            first.clone(),
+            abi::Reservation {
+                user_id: "".to_string(),
+                ..first.clone()
+            },
+            abi::Reservation {
+                id: "".to_string(),
+                user_id: "gigi".to_string(),
+                ..existing.clone()
+            },
+            abi::Reservation {
+                resource_id: "room-2".to_string(),
+                ..first.clone()
+            },
+            first,
+        ]
+    }
+
+    fn make_bundle() -> Vec<abi::Reservation> {
+        ["room-114514", "projector-1", "catering"]
+            .into_iter()
+            .map(|rid| {
+                abi::Reservation::new_pending(
+                    "kobe",
+                    rid,
+                    "2025-06-02T09:00:00Z".parse().unwrap(),
+                    "2025-06-02T12:00:00Z".parse().unwrap(),
+                    "launch party",
+                )
+            })
+            .collect()
+    }
+
+    async fn make_series(manager: &ReservationManager, rrule: &str) -> Vec<abi::Reservation> {
+        manager
+            .reserve_series(make_series_rsvp(), make_recurrence(rrule))
+            .await
+            .unwrap()
+    }
+
+    fn make_series_rsvp() -> abi::Reservation {
+        abi::Reservation::new_pending(
+            "kobe",
+            "room-114514",
+            "2025-06-02T09:00:00Z".parse().unwrap(),
+            "2025-06-02T10:00:00Z".parse().unwrap(),
+            "standup",
+        )
+    }
+
+    fn make_recurrence(rrule: &str) -> abi::Recurrence {
+        abi::Recurrence {
+            rrule: rrule.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn make_block_request(policy: abi::BlockConflictPolicy) -> abi::BlockRequest {
+        abi::BlockRequest {
+            resource_id: "room-114514".to_string(),
+            start: Some("2025-06-02T00:00:00Z".parse().unwrap()),
+            end: Some("2025-06-02T12:00:00Z".parse().unwrap()),
+            reason: "maintenance".to_string(),
+            on_conflict: policy as i32,
+        }
+    }
+
     async fn make_reservation(
         manager: &ReservationManager,
         uid: &str,
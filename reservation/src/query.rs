@@ -0,0 +1,140 @@
+use abi::{MatchMode, QueryCursor, ReservationQuery, SortBy};
+use sqlx::{Postgres, QueryBuilder};
+
+/// Table a `ReservationQuery` runs against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum QueryTable {
+    Reservations,
+    Archive,
+}
+
+impl QueryTable {
+    fn name(self) -> &'static str {
+        match self {
+            QueryTable::Reservations => "rsvp.reservations",
+            QueryTable::Archive => "rsvp.reservations_archive",
+        }
+    }
+}
+
+/// Builds the query of up to `limit` reservations after the cursor or offset of the query.
+pub(crate) fn select_page(
+    query: &ReservationQuery,
+    table: QueryTable,
+    limit: i32,
+) -> Result<QueryBuilder<'static, Postgres>, abi::Error> {
+    let mut builder = select_all(query, table)?;
+    builder
+        .push(" LIMIT ")
+        .push_bind(limit)
+        .push(" OFFSET ")
+        .push_bind(query.get_offset());
+
+    Ok(builder)
+}
+
+/// Builds the query of every reservation after the cursor of the query, ignoring paging.
+pub(crate) fn select_all(
+    query: &ReservationQuery,
+    table: QueryTable,
+) -> Result<QueryBuilder<'static, Postgres>, abi::Error> {
+    let sort_by = query.get_sort_by();
+    let sort_key = sort_key(sort_by);
+    let direction = if query.desc { "DESC" } else { "ASC" };
+
+    let mut builder = QueryBuilder::new("SELECT * FROM ");
+    builder.push(table.name());
+    push_filters(&mut builder, query, table)?;
+    if let Some(cursor) = query.get_cursor()? {
+        let op = if query.desc { "<" } else { ">" };
+        builder.push(format!(" AND ({sort_key}, id) {op} ("));
+        push_cursor_value(&mut builder, &cursor);
+        builder.push(", ").push_bind(cursor.id).push(")");
+    }
+    // ties always break on id, so every row has a unique position for the cursor.
+    builder.push(format!(" ORDER BY {sort_key} {direction}, id {direction}"));
+
+    Ok(builder)
+}
+
+/// Builds the query counting every reservation matching the filters, regardless of paging.
+pub(crate) fn select_count(
+    query: &ReservationQuery,
+    table: QueryTable,
+) -> Result<QueryBuilder<'static, Postgres>, abi::Error> {
+    let mut builder = QueryBuilder::new("SELECT count(*) FROM ");
+    builder.push(table.name());
+    push_filters(&mut builder, query, table)?;
+
+    Ok(builder)
+}
+
+/// SQL expression of the sort key, only these fixed expressions are ever put in the query.
+fn sort_key(sort_by: SortBy) -> &'static str {
+    match sort_by {
+        SortBy::Start => "lower(timespan)",
+        SortBy::End => "upper(timespan)",
+        SortBy::CreatedAt => "created_at",
+        SortBy::UpdatedAt => "updated_at",
+        SortBy::Duration => "(upper(timespan) - lower(timespan))",
+        SortBy::UserId => "user_id",
+        SortBy::ResourceId => "resource_id",
+    }
+}
+
+fn push_filters(
+    builder: &mut QueryBuilder<'static, Postgres>,
+    query: &ReservationQuery,
+    table: QueryTable,
+) -> Result<(), abi::Error> {
+    builder.push(" WHERE TRUE");
+    if !query.user_id.is_empty() {
+        builder
+            .push(" AND user_id = ")
+            .push_bind(query.user_id.clone());
+    }
+    if !query.resource_id.is_empty() {
+        builder
+            .push(" AND resource_id = ")
+            .push_bind(query.resource_id.clone());
+    }
+    if let Some(statuses) = query.get_statuses()? {
+        builder
+            .push(" AND status = ANY(")
+            .push_bind(statuses)
+            .push("::rsvp.reservation_status[])");
+    }
+
+    let range = query.get_timespan();
+    match MatchMode::try_from(query.match_mode).unwrap_or_default() {
+        MatchMode::Overlaps => builder.push(" AND timespan && ").push_bind(range),
+        MatchMode::ContainedBy => builder.push(" AND timespan <@ ").push_bind(range),
+        MatchMode::Contains => builder.push(" AND timespan @> ").push_bind(range),
+        MatchMode::StartsWithin => builder.push(" AND lower(timespan) <@ ").push_bind(range),
+    };
+
+    // archived reservations are kept whether they were deleted or not.
+    if table == QueryTable::Reservations {
+        builder.push(" AND deleted_at IS NULL");
+    }
+
+    Ok(())
+}
+
+fn push_cursor_value(builder: &mut QueryBuilder<'static, Postgres>, cursor: &QueryCursor) {
+    let value = cursor.value.clone();
+    match cursor.sort_by {
+        SortBy::Start | SortBy::End | SortBy::CreatedAt | SortBy::UpdatedAt => {
+            builder.push_bind(value).push("::timestamptz");
+        }
+        SortBy::Duration => {
+            builder
+                .push("(")
+                .push_bind(value)
+                .push("::bigint * interval '1 microsecond')");
+        }
+        SortBy::UserId | SortBy::ResourceId => {
+            builder.push_bind(value);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use std::time::Duration;
+
+use tokio::task::JoinHandle;
+
+use crate::{ReservationManager, Rsvp};
+
+impl ReservationManager {
+    /// Cancels pending reservations whose hold has expired every `period` in a background task.
+    pub fn spawn_reaper(&self, period: Duration) -> JoinHandle<()> {
+        let manager = self.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                // a failed run is simply retried on the next tick.
+                let _ = manager.expire_holds().await;
+            }
+        })
+    }
+}
@@ -0,0 +1,400 @@
+use std::{collections::VecDeque, time::Duration};
+
+use abi::{WatchRequest, WatchResponse};
+use futures::{StreamExt, stream::BoxStream};
+use sqlx::{FromRow, PgPool, Row, postgres::PgListener};
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::ReservationWatcher;
+
+/// Postgres channel notified by `rsvp.reservation_trigger` on every change.
+const CHANNEL: &str = "reservation_update";
+/// Max number of changes buffered for a subscriber before it lags behind.
+const CAPACITY: usize = 1024;
+/// Max number of changes read from the database at once.
+const BATCH_SIZE: i64 = 500;
+/// Delay before listening or reading changes again after a failure.
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+impl ReservationWatcher {
+    /// Starts listening for reservation changes in a background task.
+    ///
+    /// The task stops once every handle of the watcher is dropped or the pool is closed.
+    pub async fn start(pool: PgPool) -> Result<Self, abi::Error> {
+        let mut listener = PgListener::connect_with(&pool).await?;
+        listener.listen(CHANNEL).await?;
+        let last_id = last_change_id(&pool).await?;
+
+        let (sender, _) = broadcast::channel(CAPACITY);
+        let weak = sender.downgrade();
+        let task_pool = pool.clone();
+        tokio::spawn(async move {
+            let pool = task_pool;
+            let mut last_id = last_id;
+            loop {
+                if listener.recv().await.is_err() {
+                    if pool.is_closed() {
+                        break;
+                    }
+                    tokio::time::sleep(RETRY_DELAY).await;
+                    continue;
+                }
+                let Some(sender) = weak.upgrade() else {
+                    break;
+                };
+                // changes are read from the table rather than the notification, so one wakeup
+                // catches up with everything committed since the last read.
+                loop {
+                    let changes = match fetch_changes(&pool, last_id, BATCH_SIZE).await {
+                        Ok(changes) => changes,
+                        Err(e) => {
+                            if pool.is_closed() {
+                                return;
+                            }
+                            tracing::warn!("failed to read reservation changes: {e}");
+                            tokio::time::sleep(RETRY_DELAY).await;
+                            continue;
+                        }
+                    };
+                    let done = (changes.len() as i64) < BATCH_SIZE;
+                    for change in changes {
+                        last_id = change.change_id;
+                        // no subscribers is not an error, the change is simply dropped.
+                        let _ = sender.send(change);
+                    }
+                    if done {
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(Self { sender, pool })
+    }
+
+    /// Streams every change after `since_change_id`, then live changes as they are committed.
+    ///
+    /// If `since_change_id` is `None`, only changes committed after this call are streamed.
+    /// Changes not matching the filters of the request are skipped.
+    pub async fn watch(
+        &self,
+        request: WatchRequest,
+    ) -> Result<BoxStream<'static, Result<WatchResponse, abi::Error>>, abi::Error> {
+        // subscribe before reading the cursor, so no change falls between replay and live feed.
+        let rx = self.sender.subscribe();
+        let (last_id, replaying) = match request.since_change_id {
+            Some(id) => (id, true),
+            None => (last_change_id(&self.pool).await?, false),
+        };
+        let cursor = WatchCursor {
+            pool: self.pool.clone(),
+            rx,
+            last_id,
+            replaying,
+            backlog: VecDeque::new(),
+            request,
+        };
+
+        Ok(futures::stream::unfold(cursor, WatchCursor::next).boxed())
+    }
+}
+
+/// State of a single watch stream.
+struct WatchCursor {
+    pool: PgPool,
+    rx: broadcast::Receiver<WatchResponse>,
+    /// id of the last change seen by the stream, sent or filtered out.
+    last_id: i64,
+    /// whether changes are being read from the database instead of the live feed.
+    replaying: bool,
+    backlog: VecDeque<WatchResponse>,
+    /// filters of the stream.
+    request: WatchRequest,
+}
+
+impl WatchCursor {
+    async fn next(mut self) -> Option<(Result<WatchResponse, abi::Error>, Self)> {
+        loop {
+            if let Some(change) = self.backlog.pop_front() {
+                self.last_id = change.change_id;
+                if self.request.matches(&change) {
+                    return Some((Ok(change), self));
+                }
+                continue;
+            }
+
+            if self.replaying {
+                match fetch_changes(&self.pool, self.last_id, BATCH_SIZE).await {
+                    Ok(changes) => {
+                        self.replaying = changes.len() as i64 == BATCH_SIZE;
+                        self.backlog.extend(changes);
+                    }
+                    Err(e) => return Some((Err(e), self)),
+                }
+                continue;
+            }
+
+            match self.rx.recv().await {
+                // live changes already seen during replay are skipped.
+                Ok(change) if change.change_id > self.last_id => self.backlog.push_back(change),
+                Ok(_) => continue,
+                // fell behind the live feed, catch up from the database.
+                Err(RecvError::Lagged(_)) => self.replaying = true,
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+}
+
+/// Returns the id of the latest recorded change, or 0 if there is none.
+async fn last_change_id(pool: &PgPool) -> Result<i64, abi::Error> {
+    let id: i64 = sqlx::query("SELECT COALESCE(MAX(id), 0) FROM rsvp.reservations_changes")
+        .fetch_one(pool)
+        .await?
+        .get(0);
+
+    Ok(id)
+}
+
+/// Reads up to `limit` changes after `last_id` together with the reservation snapshot they recorded.
+///
+/// Changes recorded before snapshots were kept, whose reservation is gone, are skipped.
+async fn fetch_changes(
+    pool: &PgPool,
+    last_id: i64,
+    limit: i64,
+) -> Result<Vec<WatchResponse>, abi::Error> {
+    let rows = sqlx::query(
+        "SELECT c.id AS change_id, c.op, r.* FROM rsvp.reservations_changes c, jsonb_populate_record(NULL::rsvp.reservations, c.reservation) r WHERE c.id > $1 AND c.reservation IS NOT NULL ORDER BY c.id LIMIT $2",
+    )
+    .bind(last_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .iter()
+        .map(WatchResponse::from_row)
+        .collect::<Result<_, _>>()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::ReservationUpdateType;
+    use tokio::time::timeout;
+
+    use super::*;
+    use crate::{ReservationManager, Rsvp};
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn watcher_should_broadcast_changes() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let watcher = ReservationWatcher::start(migrated_pool.clone())
+            .await
+            .unwrap();
+        let mut stream1 = watcher.watch(WatchRequest::default()).await.unwrap();
+        let mut stream2 = watcher.watch(WatchRequest::default()).await.unwrap();
+
+        let rsvp = make_reservation(&manager, "room-114514").await;
+        manager.delete(rsvp.id.clone()).await.unwrap();
+
+        for stream in [&mut stream1, &mut stream2] {
+            let created = next_change(stream).await;
+            assert_eq!(created.op, ReservationUpdateType::Create as i32);
+            assert_eq!(created.reservation.as_ref(), Some(&rsvp));
+
+            // deleted reservations are still delivered with their last known state.
+            let deleted = next_change(stream).await;
+            assert_eq!(deleted.op, ReservationUpdateType::Delete as i32);
+            assert_eq!(unchanged(deleted.reservation.clone().unwrap(), &rsvp), rsvp);
+            assert!(deleted.change_id > created.change_id);
+        }
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn watcher_should_broadcast_reschedules() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let rsvp = make_reservation(&manager, "room-1").await;
+        let watcher = ReservationWatcher::start(migrated_pool.clone())
+            .await
+            .unwrap();
+        let mut stream = watcher.watch(WatchRequest::default()).await.unwrap();
+
+        // note changes are not recorded, moves are.
+        manager
+            .update_note(rsvp.id.clone(), "moved".to_string())
+            .await
+            .unwrap();
+        let moved = manager
+            .reschedule(abi::RescheduleRequest {
+                id: rsvp.id.clone(),
+                start: Some("2025-07-01T00:00:00Z".parse().unwrap()),
+                end: Some("2025-07-02T00:00:00Z".parse().unwrap()),
+                resource_id: "room-2".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let change = next_change(&mut stream).await;
+        assert_eq!(change.op, ReservationUpdateType::Update as i32);
+        assert_eq!(change.reservation, Some(moved));
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn watcher_should_resume_from_change_id() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let watcher = ReservationWatcher::start(migrated_pool.clone())
+            .await
+            .unwrap();
+
+        // changes made while nobody is watching.
+        let rsvp1 = make_reservation(&manager, "room-1").await;
+        let rsvp2 = make_reservation(&manager, "room-2").await;
+
+        let mut stream = watcher.watch(since(0)).await.unwrap();
+        let first = next_change(&mut stream).await;
+        assert_eq!(first.reservation, Some(rsvp1));
+        let second = next_change(&mut stream).await;
+        assert_eq!(second.reservation, Some(rsvp2.clone()));
+
+        // resume after the first change, then switch to live changes.
+        let mut stream = watcher.watch(since(first.change_id)).await.unwrap();
+        assert_eq!(next_change(&mut stream).await, second);
+        let rsvp3 = make_reservation(&manager, "room-3").await;
+        let third = next_change(&mut stream).await;
+        assert_eq!(third.reservation, Some(rsvp3));
+        assert!(third.change_id > second.change_id);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn watcher_should_skip_changes_without_snapshot() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let watcher = ReservationWatcher::start(migrated_pool.clone())
+            .await
+            .unwrap();
+
+        // a change recorded before snapshots were kept, for a reservation that no longer exists.
+        let rsvp1 = make_reservation(&manager, "room-1").await;
+        sqlx::query("INSERT INTO rsvp.reservations_changes (reservation_id, op) VALUES (gen_random_uuid(), 'DELETE')")
+            .execute(&migrated_pool)
+            .await
+            .unwrap();
+        let rsvp2 = make_reservation(&manager, "room-2").await;
+
+        let mut stream = watcher.watch(since(0)).await.unwrap();
+        assert_eq!(next_change(&mut stream).await.reservation, Some(rsvp1));
+        assert_eq!(next_change(&mut stream).await.reservation, Some(rsvp2));
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn watcher_should_replay_snapshots_without_timestamps() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let watcher = ReservationWatcher::start(migrated_pool.clone())
+            .await
+            .unwrap();
+
+        // snapshots recorded before creation and update times were tracked.
+        let rsvp = make_reservation(&manager, "room-1").await;
+        sqlx::query("UPDATE rsvp.reservations_changes SET reservation = reservation - 'created_at' - 'updated_at'")
+            .execute(&migrated_pool)
+            .await
+            .unwrap();
+
+        let mut stream = watcher.watch(since(0)).await.unwrap();
+        let change = next_change(&mut stream).await;
+        let expected = abi::Reservation {
+            created_at: None,
+            updated_at: None,
+            ..rsvp
+        };
+        assert_eq!(change.reservation, Some(expected));
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn open_transaction_should_not_hold_off_other_changes() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let watcher = ReservationWatcher::start(migrated_pool.clone())
+            .await
+            .unwrap();
+        let mut stream = watcher.watch(WatchRequest::default()).await.unwrap();
+
+        let mut tx = migrated_pool.begin().await.unwrap();
+        sqlx::query("INSERT INTO rsvp.reservations (user_id, resource_id, timespan, note) VALUES ('kobe', 'room-1', '[2025-06-01, 2025-06-02)', '')")
+            .execute(&mut tx)
+            .await
+            .unwrap();
+
+        // changes are recorded at commit, so the open transaction does not block this one.
+        let rsvp = timeout(Duration::from_secs(5), make_reservation(&manager, "room-2"))
+            .await
+            .expect("reservation should not wait for the open transaction");
+        tx.commit().await.unwrap();
+
+        let first = next_change(&mut stream).await;
+        assert_eq!(first.reservation, Some(rsvp));
+        let second = next_change(&mut stream).await;
+        assert_eq!(second.reservation.unwrap().resource_id, "room-1");
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn watcher_should_filter_changes() {
+        let manager = ReservationManager::new(migrated_pool.clone());
+        let watcher = ReservationWatcher::start(migrated_pool.clone())
+            .await
+            .unwrap();
+        let request = WatchRequest {
+            resource_prefix: "room-".to_string(),
+            ops: vec![ReservationUpdateType::Delete as i32],
+            ..since(0)
+        };
+
+        let rsvp1 = make_reservation(&manager, "room-1").await;
+        let rsvp2 = make_reservation(&manager, "car-1").await;
+        manager.delete(rsvp2.id).await.unwrap();
+
+        // replayed changes are filtered as well as live ones.
+        let mut stream = watcher.watch(request).await.unwrap();
+        manager.delete(rsvp1.id.clone()).await.unwrap();
+        let change = next_change(&mut stream).await;
+        assert_eq!(change.op, ReservationUpdateType::Delete as i32);
+        assert_eq!(unchanged(change.reservation.unwrap(), &rsvp1), rsvp1);
+    }
+
+    async fn make_reservation(manager: &ReservationManager, rid: &str) -> abi::Reservation {
+        let rsvp = abi::Reservation::new_pending(
+            "kobe",
+            rid,
+            "2025-06-01T12:00:00-07:00".parse().unwrap(),
+            "2025-06-03T12:00:00-07:00".parse().unwrap(),
+            "Man, what can I say!",
+        );
+        manager.reserve(rsvp).await.unwrap()
+    }
+
+    /// Returns the snapshot with the update time of `rsvp`, which is bumped by every change.
+    fn unchanged(snapshot: abi::Reservation, rsvp: &abi::Reservation) -> abi::Reservation {
+        let updated_at = |rsvp: &abi::Reservation| rsvp.updated_at.map(|ts| (ts.seconds, ts.nanos));
+        assert!(updated_at(&snapshot) >= updated_at(rsvp));
+        abi::Reservation {
+            updated_at: rsvp.updated_at,
+            ..snapshot
+        }
+    }
+
+    fn since(id: i64) -> WatchRequest {
+        WatchRequest {
+            since_change_id: Some(id),
+            ..Default::default()
+        }
+    }
+
+    async fn next_change(
+        stream: &mut BoxStream<'static, Result<WatchResponse, abi::Error>>,
+    ) -> WatchResponse {
+        timeout(Duration::from_secs(5), stream.next())
+            .await
+            .expect("change should arrive in time")
+            .unwrap()
+            .unwrap()
+    }
+}
@@ -0,0 +1,47 @@
+use std::env;
+
+/// Default address the gRPC server listens on.
+const DEFAULT_SERVER_ADDR: &str = "0.0.0.0:50051";
+/// Default size of the database connection pool.
+const DEFAULT_MAX_CONNECTIONS: u32 = 5;
+
+/// Runtime configuration of the reservation service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// Postgres connection url.
+    pub database_url: String,
+    /// Max connections of the database pool.
+    pub max_connections: u32,
+    /// Address the gRPC server binds to.
+    pub server_addr: String,
+    /// Archive reservations older than this many days. If `None`, nothing is archived.
+    pub archive_after_days: Option<i64>,
+}
+
+impl Config {
+    /// Loads the config from environment variables.
+    ///
+    /// `DATABASE_URL` is required, `RSVP_SERVER_ADDR`, `RSVP_DB_MAX_CONNECTIONS` and
+    /// `RSVP_ARCHIVE_AFTER_DAYS` are optional.
+    pub fn from_env() -> Result<Self, anyhow::Error> {
+        let database_url =
+            env::var("DATABASE_URL").map_err(|_| anyhow::anyhow!("DATABASE_URL is not set"))?;
+        let max_connections = match env::var("RSVP_DB_MAX_CONNECTIONS") {
+            Ok(v) => v.parse()?,
+            Err(_) => DEFAULT_MAX_CONNECTIONS,
+        };
+        let server_addr =
+            env::var("RSVP_SERVER_ADDR").unwrap_or_else(|_| DEFAULT_SERVER_ADDR.to_string());
+        let archive_after_days = match env::var("RSVP_ARCHIVE_AFTER_DAYS") {
+            Ok(v) => Some(v.parse()?),
+            Err(_) => None,
+        };
+
+        Ok(Self {
+            database_url,
+            max_connections,
+            server_addr,
+            archive_after_days,
+        })
+    }
+}
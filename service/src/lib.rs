@@ -0,0 +1,39 @@
+mod config;
+mod service;
+
+use std::time::Duration;
+
+use abi::reservation_service_server::ReservationServiceServer;
+use reservation::ReservationManager;
+use sqlx::postgres::PgPoolOptions;
+use tonic::transport::Server;
+
+pub use config::Config;
+pub use service::RsvpService;
+
+/// How often old reservations are archived.
+const ARCHIVE_PERIOD: Duration = Duration::from_secs(60 * 60);
+/// How often expired pending holds are released.
+const REAP_PERIOD: Duration = Duration::from_secs(60);
+
+/// Connects to the database and serves `ReservationService` until the server stops.
+pub async fn start_server(config: &Config) -> Result<(), anyhow::Error> {
+    let addr = config.server_addr.parse()?;
+    let pool = PgPoolOptions::new()
+        .max_connections(config.max_connections)
+        .connect(&config.database_url)
+        .await?;
+    let manager = ReservationManager::new(pool.clone());
+    if let Some(days) = config.archive_after_days {
+        manager.spawn_archiver(chrono::Duration::days(days), ARCHIVE_PERIOD);
+    }
+    manager.spawn_reaper(REAP_PERIOD);
+    let svc = RsvpService::new(pool).await?;
+
+    Server::builder()
+        .add_service(ReservationServiceServer::new(svc))
+        .serve(addr)
+        .await?;
+
+    Ok(())
+}
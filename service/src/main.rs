@@ -1,3 +1,8 @@
-fn main() {
-    println!("Hello, world!");
+use reservation_service::{Config, start_server};
+
+#[tokio::main]
+async fn main() -> Result<(), anyhow::Error> {
+    let config = Config::from_env()?;
+    println!("Reservation service listening on {}", config.server_addr);
+    start_server(&config).await
 }
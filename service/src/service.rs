@@ -0,0 +1,795 @@
+use std::pin::Pin;
+
+use abi::{
+    AvailabilityRequest, AvailabilityResponse, BlockRequest, BlockResponse, BulkTransitionRequest,
+    BulkTransitionResponse, CancelGroupRequest, CancelGroupResponse, CancelRequest, CancelResponse,
+    CancelSeriesRequest, CancelSeriesResponse, ConfirmGroupRequest, ConfirmGroupResponse,
+    ConfirmRequest, ConfirmResponse, FindSlotRequest, FindSlotResponse, GetRequest, GetResponse,
+    QueryRequest, QueryResponse, RescheduleRequest, RescheduleResponse, Reservation,
+    ReserveManyRequest, ReserveManyResponse, ReserveRequest, ReserveResponse, ReserveSeriesRequest,
+    ReserveSeriesResponse, SetBuffersRequest, SetBuffersResponse, UpdateRequest, UpdateResponse,
+    UpdateSeriesRequest, UpdateSeriesResponse, WatchRequest, WatchResponse,
+    reservation_service_server::ReservationService,
+};
+use futures::{Stream, TryStreamExt};
+use reservation::{ReservationManager, ReservationWatcher, Rsvp};
+use sqlx::PgPool;
+use tonic::{Request, Response, Status};
+
+type ReservationStream = Pin<Box<dyn Stream<Item = Result<Reservation, Status>> + Send>>;
+type WatchStream = Pin<Box<dyn Stream<Item = Result<WatchResponse, Status>> + Send>>;
+
+/// gRPC service backed by a `ReservationManager` and a `ReservationWatcher`.
+#[derive(Debug)]
+pub struct RsvpService {
+    manager: ReservationManager,
+    watcher: ReservationWatcher,
+}
+
+impl RsvpService {
+    pub async fn new(pool: PgPool) -> Result<Self, abi::Error> {
+        let watcher = ReservationWatcher::start(pool.clone()).await?;
+
+        Ok(Self {
+            manager: ReservationManager::new(pool),
+            watcher,
+        })
+    }
+}
+
+#[tonic::async_trait]
+impl ReservationService for RsvpService {
+    async fn reserve(
+        &self,
+        request: Request<ReserveRequest>,
+    ) -> Result<Response<ReserveResponse>, Status> {
+        let rsvp = request
+            .into_inner()
+            .reservation
+            .ok_or_else(|| Status::invalid_argument("missing reservation"))?;
+        let rsvp = self.manager.reserve(rsvp).await?;
+
+        Ok(Response::new(ReserveResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn reserve_many(
+        &self,
+        request: Request<ReserveManyRequest>,
+    ) -> Result<Response<ReserveManyResponse>, Status> {
+        let rsvps = self
+            .manager
+            .reserve_many(request.into_inner().reservations)
+            .await?;
+
+        Ok(Response::new(ReserveManyResponse {
+            group_id: rsvps
+                .first()
+                .map(|rsvp| rsvp.group_id.clone())
+                .unwrap_or_default(),
+            reservations: rsvps,
+        }))
+    }
+
+    async fn reserve_series(
+        &self,
+        request: Request<ReserveSeriesRequest>,
+    ) -> Result<Response<ReserveSeriesResponse>, Status> {
+        let ReserveSeriesRequest {
+            reservation,
+            recurrence,
+        } = request.into_inner();
+        let rsvp = reservation.ok_or_else(|| Status::invalid_argument("missing reservation"))?;
+        let recurrence =
+            recurrence.ok_or_else(|| Status::invalid_argument("missing recurrence"))?;
+        let rsvps = self.manager.reserve_series(rsvp, recurrence).await?;
+
+        Ok(Response::new(ReserveSeriesResponse {
+            series_id: rsvps[0].series_id.clone(),
+            reservations: rsvps,
+        }))
+    }
+
+    async fn update(
+        &self,
+        request: Request<UpdateRequest>,
+    ) -> Result<Response<UpdateResponse>, Status> {
+        let UpdateRequest { id, note } = request.into_inner();
+        let rsvp = self.manager.update_note(id, note).await?;
+
+        Ok(Response::new(UpdateResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn reschedule(
+        &self,
+        request: Request<RescheduleRequest>,
+    ) -> Result<Response<RescheduleResponse>, Status> {
+        let rsvp = self.manager.reschedule(request.into_inner()).await?;
+
+        Ok(Response::new(RescheduleResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn confirm(
+        &self,
+        request: Request<ConfirmRequest>,
+    ) -> Result<Response<ConfirmResponse>, Status> {
+        let rsvp = self.manager.change_status(request.into_inner().id).await?;
+
+        Ok(Response::new(ConfirmResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn confirm_group(
+        &self,
+        request: Request<ConfirmGroupRequest>,
+    ) -> Result<Response<ConfirmGroupResponse>, Status> {
+        let rsvps = self
+            .manager
+            .confirm_group(request.into_inner().group_id)
+            .await?;
+
+        Ok(Response::new(ConfirmGroupResponse {
+            reservations: rsvps,
+        }))
+    }
+
+    async fn cancel(
+        &self,
+        request: Request<CancelRequest>,
+    ) -> Result<Response<CancelResponse>, Status> {
+        let CancelRequest {
+            id,
+            cancelled_by,
+            reason,
+        } = request.into_inner();
+        let rsvp = self.manager.cancel(id, cancelled_by, reason).await?;
+
+        Ok(Response::new(CancelResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    async fn cancel_group(
+        &self,
+        request: Request<CancelGroupRequest>,
+    ) -> Result<Response<CancelGroupResponse>, Status> {
+        let CancelGroupRequest {
+            group_id,
+            cancelled_by,
+            reason,
+        } = request.into_inner();
+        let rsvps = self
+            .manager
+            .cancel_group(group_id, cancelled_by, reason)
+            .await?;
+
+        Ok(Response::new(CancelGroupResponse {
+            reservations: rsvps,
+        }))
+    }
+
+    async fn bulk_transition(
+        &self,
+        request: Request<BulkTransitionRequest>,
+    ) -> Result<Response<BulkTransitionResponse>, Status> {
+        let BulkTransitionRequest {
+            query,
+            status,
+            reason,
+            dry_run,
+        } = request.into_inner();
+        let status = abi::ReservationStatus::try_from(status).unwrap_or_default();
+        let rsvps = self
+            .manager
+            .bulk_transition(query.unwrap_or_default(), status, reason, dry_run)
+            .await?;
+
+        Ok(Response::new(BulkTransitionResponse {
+            reservations: rsvps,
+        }))
+    }
+
+    async fn update_series(
+        &self,
+        request: Request<UpdateSeriesRequest>,
+    ) -> Result<Response<UpdateSeriesResponse>, Status> {
+        let rsvps = self.manager.update_series(request.into_inner()).await?;
+
+        Ok(Response::new(UpdateSeriesResponse {
+            reservations: rsvps,
+        }))
+    }
+
+    async fn cancel_series(
+        &self,
+        request: Request<CancelSeriesRequest>,
+    ) -> Result<Response<CancelSeriesResponse>, Status> {
+        let CancelSeriesRequest {
+            id,
+            scope,
+            cancelled_by,
+            reason,
+        } = request.into_inner();
+        let scope = abi::SeriesScope::try_from(scope).unwrap_or_default();
+        let rsvps = self
+            .manager
+            .cancel_series(id, scope, cancelled_by, reason)
+            .await?;
+
+        Ok(Response::new(CancelSeriesResponse {
+            reservations: rsvps,
+        }))
+    }
+
+    async fn block(
+        &self,
+        request: Request<BlockRequest>,
+    ) -> Result<Response<BlockResponse>, Status> {
+        let res = self.manager.block(request.into_inner()).await?;
+
+        Ok(Response::new(res))
+    }
+
+    async fn set_buffers(
+        &self,
+        request: Request<SetBuffersRequest>,
+    ) -> Result<Response<SetBuffersResponse>, Status> {
+        self.manager.set_buffers(request.into_inner()).await?;
+
+        Ok(Response::new(SetBuffersResponse {}))
+    }
+
+    async fn availability(
+        &self,
+        request: Request<AvailabilityRequest>,
+    ) -> Result<Response<AvailabilityResponse>, Status> {
+        let slots = self.manager.availability(request.into_inner()).await?;
+
+        Ok(Response::new(AvailabilityResponse { slots }))
+    }
+
+    async fn find_slot(
+        &self,
+        request: Request<FindSlotRequest>,
+    ) -> Result<Response<FindSlotResponse>, Status> {
+        let res = self.manager.find_slot(request.into_inner()).await?;
+
+        Ok(Response::new(res))
+    }
+
+    async fn get(&self, request: Request<GetRequest>) -> Result<Response<GetResponse>, Status> {
+        let rsvp = self.manager.get(request.into_inner().id).await?;
+
+        Ok(Response::new(GetResponse {
+            reservation: Some(rsvp),
+        }))
+    }
+
+    type queryStream = ReservationStream;
+
+    async fn query(
+        &self,
+        request: Request<QueryRequest>,
+    ) -> Result<Response<Self::queryStream>, Status> {
+        let query = request
+            .into_inner()
+            .query
+            .ok_or_else(|| Status::invalid_argument("missing query"))?;
+        let stream = self.manager.query_stream(query).await?;
+
+        Ok(Response::new(Box::pin(stream.map_err(Status::from))))
+    }
+
+    async fn query_page(
+        &self,
+        request: Request<QueryRequest>,
+    ) -> Result<Response<QueryResponse>, Status> {
+        let query = request
+            .into_inner()
+            .query
+            .ok_or_else(|| Status::invalid_argument("missing query"))?;
+        let result = self.manager.query(query).await?;
+
+        Ok(Response::new(result.into()))
+    }
+
+    type watchStream = WatchStream;
+
+    async fn watch(
+        &self,
+        request: Request<WatchRequest>,
+    ) -> Result<Response<Self::watchStream>, Status> {
+        let stream = self.watcher.watch(request.into_inner()).await?;
+
+        Ok(Response::new(Box::pin(stream.map_err(Status::from))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use abi::ReservationQueryBuilder;
+    use futures::StreamExt;
+
+    use super::*;
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_reserve_and_get_should_work() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+        assert!(!rsvp.id.is_empty());
+
+        let got = service
+            .get(Request::new(GetRequest {
+                id: rsvp.id.clone(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+        assert_eq!(got, rsvp);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_update_and_confirm_should_work() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+
+        let updated = service
+            .update(Request::new(UpdateRequest {
+                id: rsvp.id.clone(),
+                note: "Mamba out!".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+        assert_eq!(updated.note, "Mamba out!");
+
+        let confirmed = service
+            .confirm(Request::new(ConfirmRequest { id: rsvp.id }))
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+        assert_eq!(confirmed.status, abi::ReservationStatus::Confirmed as i32);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_cancel_should_return_cancelled_reservation() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+
+        let cancelled = service
+            .cancel(Request::new(CancelRequest {
+                id: rsvp.id.clone(),
+                cancelled_by: "kobe".to_string(),
+                reason: "plans changed".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+        assert_eq!(cancelled.id, rsvp.id);
+        assert_eq!(cancelled.status, abi::ReservationStatus::Cancelled as i32);
+        assert_eq!(cancelled.cancellation.unwrap().reason, "plans changed");
+
+        // cancelled reservation is kept.
+        let got = service
+            .get(Request::new(GetRequest { id: rsvp.id }))
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+        assert_eq!(got.status, abi::ReservationStatus::Cancelled as i32);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_reschedule_conflict_should_carry_details() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+        let other = Reservation::new_pending(
+            "gigi",
+            "room-1",
+            "2025-06-01T12:00:00-07:00".parse().unwrap(),
+            "2025-06-03T12:00:00-07:00".parse().unwrap(),
+            "",
+        );
+        let other = service
+            .reserve(Request::new(ReserveRequest {
+                reservation: Some(other),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap();
+
+        // moving onto the resource taken by the other reservation.
+        let status = service
+            .reschedule(Request::new(RescheduleRequest {
+                id: other.id,
+                start: rsvp.start,
+                end: rsvp.end,
+                resource_id: rsvp.resource_id.clone(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::AlreadyExists);
+        let details: abi::ReservationConflictDetails = abi::decode_details(&status).unwrap();
+        assert_eq!(details.old.unwrap().resource_id, rsvp.resource_id);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_reserve_many_should_reserve_together() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+        let make = |rid: &str| Reservation {
+            resource_id: rid.to_string(),
+            id: "".to_string(),
+            ..rsvp.clone()
+        };
+
+        let status = service
+            .reserve_many(Request::new(ReserveManyRequest {
+                reservations: vec![make("projector-1"), make("room-114514")],
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::AlreadyExists);
+        let details: abi::ReservationConflictsDetails = abi::decode_details(&status).unwrap();
+        assert_eq!(details.conflicts.len(), 1);
+
+        let res = service
+            .reserve_many(Request::new(ReserveManyRequest {
+                reservations: vec![make("projector-1"), make("room-1")],
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(res.reservations.len(), 2);
+        assert_eq!(res.reservations[1].group_id, res.group_id);
+
+        let res = service
+            .confirm_group(Request::new(ConfirmGroupRequest {
+                group_id: res.group_id,
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert!(
+            res.reservations
+                .iter()
+                .all(|rsvp| rsvp.status == abi::ReservationStatus::Confirmed as i32)
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_bulk_transition_should_cancel_matches() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+        let query = abi::ReservationQuery {
+            resource_id: rsvp.resource_id.clone(),
+            ..Default::default()
+        };
+        let request = |dry_run| {
+            Request::new(BulkTransitionRequest {
+                query: Some(query.clone()),
+                status: abi::ReservationStatus::Cancelled as i32,
+                reason: "closed".to_string(),
+                dry_run,
+            })
+        };
+
+        let res = service.bulk_transition(request(true)).await.unwrap();
+        assert_eq!(res.into_inner().reservations.len(), 1);
+        let res = service.bulk_transition(request(false)).await.unwrap();
+        let rsvps = res.into_inner().reservations;
+        assert_eq!(rsvps.len(), 1);
+        assert_eq!(rsvps[0].id, rsvp.id);
+        assert_eq!(rsvps[0].status, abi::ReservationStatus::Cancelled as i32);
+
+        // nothing is left to cancel.
+        let res = service.bulk_transition(request(false)).await.unwrap();
+        assert!(res.into_inner().reservations.is_empty());
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_reserve_series_should_report_conflicts() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+        let first = Reservation::new_pending(
+            "gigi",
+            "room-114514",
+            "2025-05-31T12:00:00-07:00".parse().unwrap(),
+            "2025-05-31T13:00:00-07:00".parse().unwrap(),
+            "",
+        );
+        let mut request = ReserveSeriesRequest {
+            reservation: Some(first),
+            recurrence: Some(abi::Recurrence {
+                rrule: "FREQ=DAILY;COUNT=4".to_string(),
+                ..Default::default()
+            }),
+        };
+
+        // the two occurrences during the existing reservation conflict.
+        let status = service
+            .reserve_series(Request::new(request.clone()))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::AlreadyExists);
+        let details: abi::ReservationConflictsDetails = abi::decode_details(&status).unwrap();
+        assert_eq!(details.conflicts.len(), 2);
+        assert_eq!(details.conflicts[0].old.as_ref().unwrap().start, rsvp.start);
+
+        request.recurrence.as_mut().unwrap().exdates = vec![
+            "2025-06-01T19:00:00Z".parse().unwrap(),
+            "2025-06-02T19:00:00Z".parse().unwrap(),
+        ];
+        let res = service
+            .reserve_series(Request::new(request))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(res.reservations.len(), 2);
+        assert_eq!(res.reservations[1].series_id, res.series_id);
+
+        let res = service
+            .cancel_series(Request::new(CancelSeriesRequest {
+                id: res.reservations[0].id.clone(),
+                scope: abi::SeriesScope::All as i32,
+                cancelled_by: "gigi".to_string(),
+                reason: "".to_string(),
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(res.reservations.len(), 2);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_block_should_report_overlapping_reservations() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+        let mut request = BlockRequest {
+            resource_id: "room-114514".to_string(),
+            start: Some("2025-06-02T00:00:00Z".parse().unwrap()),
+            end: Some("2025-06-02T12:00:00Z".parse().unwrap()),
+            reason: "cleaning".to_string(),
+            ..Default::default()
+        };
+
+        let status = service
+            .block(Request::new(request.clone()))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::AlreadyExists);
+        assert!(status.message().contains(&rsvp.id));
+
+        request.on_conflict = abi::BlockConflictPolicy::Cancel as i32;
+        let res = service
+            .block(Request::new(request))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(res.block.unwrap().user_id, abi::SYSTEM_USER_ID);
+        assert_eq!(res.cancelled[0].id, rsvp.id);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_availability_should_return_free_slots() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+        let mut request = AvailabilityRequest {
+            resource_id: "room-114514".to_string(),
+            start: Some("2025-06-01T00:00:00Z".parse().unwrap()),
+            end: Some("2025-06-04T00:00:00Z".parse().unwrap()),
+            ..Default::default()
+        };
+
+        let res = service
+            .availability(Request::new(request.clone()))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(res.slots.len(), 2);
+        assert_eq!(res.slots[0].start, request.start);
+        assert_eq!(res.slots[0].end, rsvp.start);
+        assert_eq!(res.slots[1].start, rsvp.end);
+        assert_eq!(res.slots[1].end, request.end);
+
+        request.granularity = Some(abi::Duration::default());
+        let status = service
+            .availability(Request::new(request))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_find_slot_should_reserve_free_candidate() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+        let template = Reservation::new_pending(
+            "gigi",
+            "",
+            "2025-06-01T00:00:00Z".parse().unwrap(),
+            "2025-06-01T00:00:00Z".parse().unwrap(),
+            "",
+        );
+        let request = FindSlotRequest {
+            resource_ids: vec!["room-114514".to_string(), "room-1".to_string()],
+            start: rsvp.start,
+            end: rsvp.end,
+            duration: Some(abi::Duration {
+                seconds: 3600,
+                nanos: 0,
+            }),
+            reservation: Some(template),
+            ..Default::default()
+        };
+
+        let res = service
+            .find_slot(Request::new(request))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(res.resource_id, "room-1");
+        let reserved = res.reservation.unwrap();
+        assert_eq!(reserved.user_id, "gigi");
+        assert_eq!(reserved.resource_id, "room-1");
+        assert_eq!(reserved.start, rsvp.start);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_buffer_conflict_should_be_reported() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let buffers = |seconds| SetBuffersRequest {
+            resource_id: "room-114514".to_string(),
+            before: Some(abi::Duration { seconds, nanos: 0 }),
+            after: None,
+        };
+        let status = service
+            .set_buffers(Request::new(buffers(-1)))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+        service
+            .set_buffers(Request::new(buffers(3600)))
+            .await
+            .unwrap();
+        make_reservation(&service).await;
+
+        let rsvp = Reservation::new_pending(
+            "gigi",
+            "room-114514",
+            "2025-06-01T10:00:00-07:00".parse().unwrap(),
+            "2025-06-01T11:30:00-07:00".parse().unwrap(),
+            "",
+        );
+        let status = service
+            .reserve(Request::new(ReserveRequest {
+                reservation: Some(rsvp),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::AlreadyExists);
+        let details: abi::ReservationConflictDetails = abi::decode_details(&status).unwrap();
+        assert!(details.buffered);
+        assert_eq!(
+            details.old.unwrap().start,
+            Some("2025-06-01T18:00:00Z".parse().unwrap())
+        );
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_query_should_stream_reservations() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+
+        let query = ReservationQueryBuilder::default()
+            .user_id("kobe")
+            .start("2025-06-01T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+            .end("2025-06-04T00:00:00Z".parse::<abi::Timestamp>().unwrap())
+            .status(abi::ReservationStatus::Pending as i32)
+            .build()
+            .unwrap();
+        let response = service
+            .query(Request::new(QueryRequest { query: Some(query) }))
+            .await
+            .unwrap();
+        let rsvps: Vec<_> = response.into_inner().map(|r| r.unwrap()).collect().await;
+        assert_eq!(rsvps, vec![rsvp]);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_query_page_should_return_paging_info() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let rsvp = make_reservation(&service).await;
+
+        let query = ReservationQueryBuilder::default()
+            .user_id("kobe")
+            .with_total_count(true)
+            .build()
+            .unwrap();
+        let res = service
+            .query_page(Request::new(QueryRequest { query: Some(query) }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_eq!(res.reservations, vec![rsvp]);
+        assert_eq!(res.total_count, Some(1));
+        assert!(!res.has_more);
+        assert!(res.next_cursor.is_empty());
+        assert_eq!(res.next_page, 0);
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_conflict_reserve_should_return_details() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        make_reservation(&service).await;
+
+        let rsvp = Reservation::new_pending(
+            "man",
+            "room-114514",
+            "2025-06-02T12:00:00-07:00".parse().unwrap(),
+            "2025-06-05T12:00:00-07:00".parse().unwrap(),
+            "hi.",
+        );
+        let status = service
+            .reserve(Request::new(ReserveRequest {
+                reservation: Some(rsvp),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), tonic::Code::AlreadyExists);
+
+        let details: abi::ReservationConflictDetails = abi::decode_details(&status).unwrap();
+        assert_eq!(details.old.unwrap().resource_id, "room-114514");
+    }
+
+    #[sqlx_database_tester::test(pool(variable = "migrated_pool", migrations = "../migrations"))]
+    async fn rpc_watch_should_stream_changes() {
+        let service = RsvpService::new(migrated_pool.clone()).await.unwrap();
+        let mut stream = service
+            .watch(Request::new(WatchRequest::default()))
+            .await
+            .unwrap()
+            .into_inner();
+
+        let rsvp = make_reservation(&service).await;
+        let change = stream.next().await.unwrap().unwrap();
+        assert_eq!(change.op, abi::ReservationUpdateType::Create as i32);
+        assert_eq!(change.reservation, Some(rsvp));
+    }
+
+    async fn make_reservation(service: &RsvpService) -> Reservation {
+        let rsvp = Reservation::new_pending(
+            "kobe",
+            "room-114514",
+            "2025-06-01T12:00:00-07:00".parse().unwrap(),
+            "2025-06-03T12:00:00-07:00".parse().unwrap(),
+            "Man, what can I say!",
+        );
+        service
+            .reserve(Request::new(ReserveRequest {
+                reservation: Some(rsvp),
+            }))
+            .await
+            .unwrap()
+            .into_inner()
+            .reservation
+            .unwrap()
+    }
+}